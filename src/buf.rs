@@ -0,0 +1,90 @@
+use bytes::{Buf, BufMut};
+
+use crate::{
+    byte_to_hex_bytes,
+    decoder::{DecodeStatus, Decoder},
+    requires_escape, DecodeError,
+};
+
+/// Tick-encode `input`, appending the result to `out`. Returns the number
+/// of bytes appended.
+///
+/// This is the [`BufMut`] counterpart to [`crate::encode_to_vec`], letting
+/// callers encode directly into a `BytesMut` (or any other `BufMut` sink)
+/// without an intermediate `Vec<u8>`.
+///
+/// ## Example
+///
+/// ```
+/// # #![cfg(feature = "bytes")]
+/// use bytes::BytesMut;
+///
+/// let mut out = BytesMut::new();
+/// let count = tick_encoding::buf::encode_to_buf(&[0x00, 0xFF], &mut out);
+/// assert_eq!(out, &b"`00`FF"[..]);
+/// assert_eq!(count, 6);
+/// ```
+pub fn encode_to_buf<B: BufMut>(input: &[u8], out: &mut B) -> usize {
+    let mut written = 0;
+    for &byte in input {
+        if byte == b'`' {
+            out.put_slice(b"``");
+            written += 2;
+        } else if requires_escape(byte) {
+            let [high, low] = byte_to_hex_bytes(byte);
+            out.put_u8(b'`');
+            out.put_u8(high);
+            out.put_u8(low);
+            written += 3;
+        } else {
+            out.put_u8(byte);
+            written += 1;
+        }
+    }
+
+    written
+}
+
+/// Decode the tick-encoded bytes remaining in `input`, appending the
+/// decoded bytes to `out`. Returns the number of bytes appended, or an
+/// error if `input` isn't a valid canonical tick-encoding.
+///
+/// This is the [`Buf`] counterpart to [`crate::decode_to_vec`]. `input` is
+/// read one byte at a time via [`Buf::get_u8`], so a `` ` `` escape that
+/// happens to straddle two of `input`'s underlying chunks is handled
+/// transparently.
+///
+/// ## Example
+///
+/// ```
+/// # #![cfg(feature = "bytes")]
+/// use bytes::BytesMut;
+///
+/// let mut input = &b"hello `00`FF"[..];
+/// let mut out = BytesMut::new();
+/// let count = tick_encoding::buf::decode_from_buf(&mut input, &mut out).unwrap();
+/// assert_eq!(out, &b"hello \x00\xFF"[..]);
+/// assert_eq!(count, 8);
+/// ```
+pub fn decode_from_buf<B: Buf>(
+    input: &mut B,
+    out: &mut impl BufMut,
+) -> Result<usize, DecodeError> {
+    let mut decoder = Decoder::default();
+    let mut written = 0;
+
+    loop {
+        let byte = input.has_remaining().then(|| input.get_u8());
+        match decoder.push(byte) {
+            DecodeStatus::NeedMore => {}
+            DecodeStatus::Emit(None) => break,
+            DecodeStatus::Emit(Some(Ok(byte))) => {
+                out.put_u8(byte);
+                written += 1;
+            }
+            DecodeStatus::Emit(Some(Err(err))) => return Err(err),
+        }
+    }
+
+    Ok(written)
+}
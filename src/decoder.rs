@@ -1,65 +1,474 @@
-use crate::{hex_bytes_to_byte, requires_escape, DecodeError};
+//! A push-based decoder state machine, mirroring [`crate::encoder::Encoder`]
+//! but for the decoding direction.
 
+use crate::{hex_bytes_to_byte_with, requires_escape, DecodeError};
+
+/// Decodes tick-encoded bytes fed to it one at a time, across arbitrary
+/// chunk boundaries (so a `` ` `` escape split between two reads still
+/// decodes correctly). [`crate::decode_iter`]'s iterator is a thin wrapper
+/// over this state machine.
+///
+/// ## Example
+///
+/// ```
+/// use tick_encoding::decoder::Decoder;
+///
+/// let mut decoder = Decoder::default();
+/// assert_eq!(decoder.feed(b'h').unwrap(), Some(b'h'));
+/// assert_eq!(decoder.feed(b'`').unwrap(), None);
+/// assert_eq!(decoder.feed(b'0').unwrap(), None);
+/// assert_eq!(decoder.feed(b'0').unwrap(), Some(0x00));
+/// decoder.finish().unwrap();
+/// ```
+///
+/// With [`DecodeOptions::lossy`], malformed input is repaired instead of
+/// erroring:
+///
+/// ```
+/// use tick_encoding::decoder::{DecodeOptions, Decoder};
+///
+/// let options = DecodeOptions::new().lossy(true).replacement(b'?');
+/// let mut decoder = Decoder::with_options(options);
+/// assert_eq!(decoder.feed(0xFF).unwrap(), Some(b'?'));
+/// assert_eq!(decoder.repaired_count(), 1);
+/// ```
 #[derive(Debug, Default, Clone, Copy)]
-pub enum Decoder {
-    #[default]
-    Ready,
-    Finished,
-    Tick,
-    TickHalfHex(u8),
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Decoder {
+    state: Progress,
+    options: DecodeOptions,
+    /// A byte produced while recovering from malformed input in
+    /// [`DecodeOptions::lossy`] mode, queued up to be returned from
+    /// [`pop_repaired`](Self::pop_repaired) without consuming any further
+    /// input. Mirrors [`Encoder::next`](crate::encoder::Encoder::next).
+    queued: Option<u8>,
+    /// Running count of bytes repaired (replaced or skipped) in
+    /// [`DecodeOptions::lossy`] mode. See [`repaired_count`](Self::repaired_count).
+    repaired: usize,
 }
 
 impl Decoder {
+    /// Create a decoder that uses `options` to decide how to handle
+    /// non-canonical escapes (see [`DecodeOptions`]).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tick_encoding::decoder::{DecodeOptions, Decoder};
+    ///
+    /// let mut decoder = Decoder::with_options(DecodeOptions::new().lenient(true));
+    /// assert_eq!(decoder.feed(b'`').unwrap(), None);
+    /// assert_eq!(decoder.feed(b'6').unwrap(), None);
+    /// assert_eq!(decoder.feed(b'5').unwrap(), Some(b'e'));
+    /// ```
+    pub fn with_options(options: DecodeOptions) -> Self {
+        Self {
+            state: Progress::default(),
+            options,
+            queued: None,
+            repaired: 0,
+        }
+    }
+
+    /// Extract a checkpoint of this decoder's progress through (or outside
+    /// of) a `` ` `` escape, for persisting across a process restart or
+    /// async suspension point. Pair with [`from_state`](Self::from_state)
+    /// to resume decoding later, picking up mid-escape if needed.
+    ///
+    /// This only covers the escape progress itself; the caller is expected
+    /// to persist [`DecodeOptions`] (used to reconstruct the decoder via
+    /// `from_state`) separately, e.g. alongside the checkpoint.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tick_encoding::decoder::{DecodeOptions, Decoder, DecoderState};
+    ///
+    /// let mut decoder = Decoder::default();
+    /// assert_eq!(decoder.feed(b'`').unwrap(), None);
+    /// assert_eq!(decoder.feed(b'F').unwrap(), None);
+    /// assert_eq!(decoder.into_state(), DecoderState::PendingHexDigit { high: b'F' });
+    /// ```
+    pub fn into_state(self) -> DecoderState {
+        self.state.into()
+    }
+
+    /// Reconstruct a decoder from a checkpoint previously taken with
+    /// [`into_state`](Self::into_state), resuming with `options`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tick_encoding::decoder::{DecodeOptions, Decoder, DecoderState};
+    ///
+    /// let mut decoder =
+    ///     Decoder::from_state(DecoderState::PendingHexDigit { high: b'F' }, DecodeOptions::new());
+    /// assert_eq!(decoder.feed(b'F').unwrap(), Some(0xFF));
+    /// ```
+    pub fn from_state(state: DecoderState, options: DecodeOptions) -> Self {
+        Self {
+            state: state.into(),
+            options,
+            queued: None,
+            repaired: 0,
+        }
+    }
+
+    /// Returns true if this decoder is in the middle of a `` ` `` escape
+    /// sequence (i.e. neither [`Ready`](Progress::Ready) nor
+    /// [`Finished`](Progress::Finished)).
+    pub(crate) fn is_pending(&self) -> bool {
+        self.state.is_pending()
+    }
+
+    /// Returns the number of bytes repaired (replaced or skipped) so far
+    /// while recovering from malformed input. Always `0` unless
+    /// [`DecodeOptions::lossy`] is enabled.
+    pub fn repaired_count(&self) -> usize {
+        self.repaired
+    }
+
+    /// Feed a single byte into the decoder. Returns `Ok(Some(byte))` once
+    /// `byte` completes a decoded byte (or is itself one), or `Ok(None)`
+    /// while in the middle of a `` ` `` escape sequence that `byte` didn't
+    /// complete.
+    pub fn feed(&mut self, byte: u8) -> Result<Option<u8>, DecodeError> {
+        match self.push(Some(byte)) {
+            DecodeStatus::NeedMore | DecodeStatus::Emit(None) => Ok(None),
+            DecodeStatus::Emit(Some(result)) => result.map(Some),
+        }
+    }
+
+    /// Pop a byte produced "for free" while recovering from malformed input
+    /// in [`DecodeOptions::lossy`] mode, without consuming any further
+    /// input. Callers that drive [`push`](Self::push) directly should drain
+    /// this after every call, the same way [`Encoder::next`] is drained
+    /// after every [`Encoder::push`].
+    ///
+    /// [`Encoder::next`]: crate::encoder::Encoder::next
+    /// [`Encoder::push`]: crate::encoder::Encoder::push
+    pub fn pop_repaired(&mut self) -> Option<Result<u8, DecodeError>> {
+        self.queued.take().map(Ok)
+    }
+
+    /// Finish decoding, returning [`DecodeError::UnexpectedEnd`] if called
+    /// while in the middle of a `` ` `` escape sequence (unless
+    /// [`DecodeOptions::lossy`] is enabled, in which case a dangling escape
+    /// is silently dropped instead).
+    pub fn finish(self) -> Result<(), DecodeError> {
+        if self.is_pending() && !self.options.is_lossy() {
+            Err(DecodeError::UnexpectedEnd)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Decode as much of `input` as fits in `output`, returning
+    /// `(bytes_consumed, bytes_written)`.
+    ///
+    /// This is the allocation-free, block-oriented counterpart to
+    /// [`push`](Self::push): it drives the same state machine, but across a
+    /// whole slice at once instead of one byte at a time, so a `` ` ``
+    /// escape (or half-decoded hex pair) split across two `decode_slice`
+    /// calls still decodes correctly. Pass `eof: true` once `input` holds
+    /// the last of the data to decode, to catch a dangling escape left
+    /// incomplete at the end (see [`finish`](Self::finish)).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tick_encoding::decoder::Decoder;
+    ///
+    /// let mut decoder = Decoder::default();
+    /// let mut output = [0u8; 3];
+    /// let (consumed, written) = decoder.decode_slice(b"hi`00", &mut output, true).unwrap();
+    /// assert_eq!(consumed, 5);
+    /// assert_eq!(&output[..written], b"hi\x00");
+    /// ```
+    pub fn decode_slice(
+        &mut self,
+        input: &[u8],
+        output: &mut [u8],
+        eof: bool,
+    ) -> Result<(usize, usize), DecodeError> {
+        let mut consumed = 0;
+        let mut written = 0;
+
+        loop {
+            if written >= output.len() {
+                break;
+            }
+
+            // Drain any byte queued by a previous lossy repair before
+            // consuming further input, mirroring `Encoder::encode_slice`.
+            if let Some(queued) = self.pop_repaired() {
+                output[written] = queued?;
+                written += 1;
+                continue;
+            }
+
+            let Some(&byte) = input.get(consumed) else {
+                break;
+            };
+
+            match self.push(Some(byte)) {
+                DecodeStatus::NeedMore => {
+                    consumed += 1;
+                }
+                DecodeStatus::Emit(None) => {
+                    return Ok((consumed, written));
+                }
+                DecodeStatus::Emit(Some(Ok(decoded))) => {
+                    consumed += 1;
+                    output[written] = decoded;
+                    written += 1;
+                }
+                DecodeStatus::Emit(Some(Err(err))) => return Err(err),
+            }
+        }
+
+        if eof && consumed == input.len() {
+            if let DecodeStatus::Emit(Some(Err(err))) = self.push(None) {
+                return Err(err);
+            }
+        }
+
+        Ok((consumed, written))
+    }
+
     pub fn push(&mut self, input: Option<u8>) -> DecodeStatus {
-        match (*self, input) {
-            (Self::Finished, _) => DecodeStatus::Emit(None),
-            (Self::Ready, Some(input)) => {
+        match (self.state, input) {
+            (Progress::Finished, _) => DecodeStatus::Emit(None),
+            (Progress::Ready, Some(input)) => {
                 if input == b'`' {
-                    *self = Self::Tick;
+                    self.state = Progress::Tick;
                     DecodeStatus::NeedMore
                 } else if requires_escape(input) {
-                    *self = Self::Finished;
-                    DecodeStatus::Emit(Some(Err(DecodeError::InvalidByte(input))))
+                    if self.options.is_lossy() {
+                        self.repaired += 1;
+                        let replacement = self.options.replacement_byte().unwrap_or(input);
+                        DecodeStatus::Emit(Some(Ok(replacement)))
+                    } else {
+                        self.state = Progress::Finished;
+                        DecodeStatus::Emit(Some(Err(DecodeError::InvalidByte(input))))
+                    }
                 } else {
                     DecodeStatus::Emit(Some(Ok(input)))
                 }
             }
-            (Self::Ready, None) => {
-                *self = Self::Finished;
+            (Progress::Ready, None) => {
+                self.state = Progress::Finished;
                 DecodeStatus::Emit(None)
             }
-            (Self::Tick, Some(input)) => {
+            (Progress::Tick, Some(input)) => {
                 if input == b'`' {
-                    *self = Self::Ready;
+                    self.state = Progress::Ready;
                     DecodeStatus::Emit(Some(Ok(b'`')))
                 } else {
-                    *self = Self::TickHalfHex(input);
+                    self.state = Progress::TickHalfHex(input);
                     DecodeStatus::NeedMore
                 }
             }
-            (Self::Tick, None) => {
-                *self = Self::Finished;
-                DecodeStatus::Emit(Some(Err(DecodeError::UnexpectedEnd)))
+            (Progress::Tick, None) => {
+                if self.options.is_lossy() {
+                    self.repaired += 1;
+                    self.state = Progress::Finished;
+                    DecodeStatus::Emit(None)
+                } else {
+                    self.state = Progress::Finished;
+                    DecodeStatus::Emit(Some(Err(DecodeError::UnexpectedEnd)))
+                }
             }
-            (Self::TickHalfHex(high), Some(low)) => {
-                let byte_result = hex_bytes_to_byte([high, low]);
+            (Progress::TickHalfHex(high), Some(low)) => {
+                let byte_result = hex_bytes_to_byte_with([high, low], &self.options);
                 match byte_result {
                     Ok(byte) => {
-                        *self = Self::Ready;
+                        self.state = Progress::Ready;
                         DecodeStatus::Emit(Some(Ok(byte)))
                     }
+                    Err(DecodeError::InvalidHex(_)) if self.options.is_lossy() => {
+                        self.repaired += 1;
+                        self.state = Progress::Ready;
+                        let resolved = self.reprocess_as_ready(low);
+                        match self.options.replacement_byte() {
+                            Some(replacement) => {
+                                self.queued = resolved;
+                                DecodeStatus::Emit(Some(Ok(replacement)))
+                            }
+                            None => match resolved {
+                                Some(byte) => DecodeStatus::Emit(Some(Ok(byte))),
+                                None => DecodeStatus::NeedMore,
+                            },
+                        }
+                    }
                     Err(error) => {
-                        *self = Self::Finished;
+                        self.state = Progress::Finished;
                         DecodeStatus::Emit(Some(Err(error)))
                     }
                 }
             }
-            (Self::TickHalfHex(_), None) => {
-                *self = Self::Finished;
-                DecodeStatus::Emit(Some(Err(DecodeError::UnexpectedEnd)))
+            (Progress::TickHalfHex(_), None) => {
+                if self.options.is_lossy() {
+                    self.repaired += 1;
+                    self.state = Progress::Finished;
+                    DecodeStatus::Emit(None)
+                } else {
+                    self.state = Progress::Finished;
+                    DecodeStatus::Emit(Some(Err(DecodeError::UnexpectedEnd)))
+                }
             }
         }
     }
+
+    /// Re-examine `byte` as a fresh [`Ready`](Progress::Ready) input, as
+    /// part of recovering from a broken `` ` `` escape in lossy mode (so a
+    /// stray backtick right after the broken escape still starts a new one,
+    /// instead of being silently dropped). Only called once `self.options`
+    /// is already known to be lossy, so unlike the `Ready` arm in
+    /// [`push`](Self::push), this never errors.
+    fn reprocess_as_ready(&mut self, byte: u8) -> Option<u8> {
+        if byte == b'`' {
+            self.state = Progress::Tick;
+            None
+        } else if requires_escape(byte) {
+            self.repaired += 1;
+            Some(self.options.replacement_byte().unwrap_or(byte))
+        } else {
+            Some(byte)
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Progress {
+    #[default]
+    Ready,
+    Finished,
+    Tick,
+    TickHalfHex(u8),
+}
+
+impl Progress {
+    fn is_pending(&self) -> bool {
+        matches!(self, Self::Tick | Self::TickHalfHex(_))
+    }
+}
+
+/// A checkpoint of a [`Decoder`]'s progress through (or outside of) a
+/// `` ` `` escape, returned by [`Decoder::into_state`] and accepted by
+/// [`Decoder::from_state`]. This is a stable, purpose-built shape for
+/// persisting a decode across a process restart or async suspension point,
+/// decoupled from the decoder's actual internal representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DecoderState {
+    /// Not in the middle of an escape; ready to decode the next byte.
+    Ready,
+    /// Decoding has finished; no further input will be accepted.
+    Finished,
+    /// In the middle of a `` ` `` escape, waiting for its first hex digit.
+    PendingEscape,
+    /// In the middle of a `` ` `` escape, having read `high` as its first
+    /// hex digit and waiting for the second.
+    PendingHexDigit {
+        /// The first (high nibble) hex digit already read.
+        high: u8,
+    },
+}
+
+impl From<Progress> for DecoderState {
+    fn from(state: Progress) -> Self {
+        match state {
+            Progress::Ready => Self::Ready,
+            Progress::Finished => Self::Finished,
+            Progress::Tick => Self::PendingEscape,
+            Progress::TickHalfHex(high) => Self::PendingHexDigit { high },
+        }
+    }
+}
+
+impl From<DecoderState> for Progress {
+    fn from(state: DecoderState) -> Self {
+        match state {
+            DecoderState::Ready => Self::Ready,
+            DecoderState::Finished => Self::Finished,
+            DecoderState::PendingEscape => Self::Tick,
+            DecoderState::PendingHexDigit { high } => Self::TickHalfHex(high),
+        }
+    }
+}
+
+/// Options controlling how [`Decoder`] (and the free functions built on top
+/// of it, like [`crate::decode_with`]) handle non-canonical tick-encoded
+/// input.
+///
+/// By default, options are strict, matching the behavior of the free
+/// [`crate::decode`] function.
+///
+/// ## Example
+///
+/// ```
+/// use tick_encoding::decoder::DecodeOptions;
+///
+/// let options = DecodeOptions::new().lenient(true);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecodeOptions {
+    lenient: bool,
+    lossy: bool,
+    replacement: Option<u8>,
+}
+
+impl DecodeOptions {
+    /// Create a new, strict set of options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// If `true`, accept lowercase `[a-f]` hex digits and "redundant"
+    /// escapes of bytes that don't require escaping (e.g. `` `65 `` for
+    /// `e`), folding them into the decoded byte instead of erroring.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    pub(crate) fn is_lenient(&self) -> bool {
+        self.lenient
+    }
+
+    /// If `true`, recover from malformed input (an invalid byte, an invalid
+    /// hex digit in an escape, or a dangling `` ` `` at the end of the
+    /// input) instead of erroring. An invalid byte is replaced (see
+    /// [`replacement`](Self::replacement)) or passed through verbatim; an
+    /// escape with an invalid hex digit is replaced or skipped, and its
+    /// final byte is then decoded afresh as ordinary input; a dangling
+    /// escape at the end emits nothing. This doesn't affect the escapes governed by
+    /// [`lenient`](Self::lenient) (lowercase or redundant escapes), which
+    /// are already accepted outright when `lenient` is set.
+    pub fn lossy(mut self, lossy: bool) -> Self {
+        self.lossy = lossy;
+        self
+    }
+
+    pub(crate) fn is_lossy(&self) -> bool {
+        self.lossy
+    }
+
+    /// Set the byte substituted for malformed input when
+    /// [`lossy`](Self::lossy) is enabled. If never set, an invalid raw byte
+    /// is passed through verbatim, and a broken `` ` `` escape is skipped
+    /// entirely (no byte is emitted for it).
+    pub fn replacement(mut self, replacement: u8) -> Self {
+        self.replacement = Some(replacement);
+        self
+    }
+
+    pub(crate) fn replacement_byte(&self) -> Option<u8> {
+        self.replacement
+    }
 }
 
 pub enum DecodeStatus {
@@ -1,6 +1,21 @@
 use crate::{nibble_to_hex, requires_escape};
 
-/// Encoder state machine.
+/// A push-based encoder state machine, mirroring [`crate::decoder::Decoder`]
+/// but for the encoding direction. [`crate::encode_iter`]'s iterator is a
+/// thin wrapper over this state machine.
+///
+/// ## Example
+///
+/// ```
+/// use tick_encoding::encoder::Encoder;
+///
+/// let mut encoder = Encoder::default();
+/// assert_eq!(encoder.push(b'h'), 'h');
+/// assert_eq!(encoder.push(0x00), '`');
+/// assert_eq!(encoder.next(), Some('0'));
+/// assert_eq!(encoder.next(), Some('0'));
+/// assert_eq!(encoder.next(), None);
+/// ```
 #[derive(Debug, Clone, Copy)]
 pub struct Encoder {
     /// Precomputed characters to emit.
@@ -20,6 +35,56 @@ impl Default for Encoder {
 }
 
 impl Encoder {
+    /// Extract a checkpoint of this encoder's progress through (or outside
+    /// of) a pending escape, for persisting across a process restart or
+    /// async suspension point. Pair with [`from_state`](Self::from_state) to
+    /// resume encoding later, picking up any not-yet-emitted escape
+    /// characters.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tick_encoding::encoder::{Encoder, EncoderState};
+    ///
+    /// let mut encoder = Encoder::default();
+    /// assert_eq!(encoder.push(0x00), '`');
+    /// assert_eq!(encoder.into_state(), EncoderState::PendingHexDigits('0', '0'));
+    /// ```
+    pub fn into_state(self) -> EncoderState {
+        match self.pending {
+            0 => EncoderState::Ready,
+            1 => EncoderState::PendingChar(self.chars[1] as char),
+            _ => EncoderState::PendingHexDigits(self.chars[0] as char, self.chars[1] as char),
+        }
+    }
+
+    /// Reconstruct an encoder from a checkpoint previously taken with
+    /// [`into_state`](Self::into_state).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tick_encoding::encoder::{Encoder, EncoderState};
+    ///
+    /// let mut encoder = Encoder::from_state(EncoderState::PendingHexDigits('0', '0'));
+    /// assert_eq!(encoder.next(), Some('0'));
+    /// assert_eq!(encoder.next(), Some('0'));
+    /// assert_eq!(encoder.next(), None);
+    /// ```
+    pub fn from_state(state: EncoderState) -> Self {
+        match state {
+            EncoderState::Ready => Self::default(),
+            EncoderState::PendingChar(c) => Self {
+                chars: [0, c as u8],
+                pending: 1,
+            },
+            EncoderState::PendingHexDigits(high, low) => Self {
+                chars: [high as u8, low as u8],
+                pending: 2,
+            },
+        }
+    }
+
     #[inline]
     pub fn next(&mut self) -> Option<char> {
         if self.pending == 0 {
@@ -35,6 +100,63 @@ impl Encoder {
         Some(self.chars[index] as char)
     }
 
+    /// Returns true if there are still pending characters waiting to be
+    /// returned from [`next`](Self::next).
+    #[inline]
+    pub(crate) fn has_pending(&self) -> bool {
+        self.pending != 0
+    }
+
+    /// Encode as much of `input` as fits in `output`, returning
+    /// `(bytes_consumed, bytes_written)`.
+    ///
+    /// This is the allocation-free, block-oriented counterpart to
+    /// [`push`](Self::push)/[`next`](Self::next): it drives the same state
+    /// machine, but across a whole slice at once instead of one byte at a
+    /// time. If `output` fills up before all of `input` is consumed, the
+    /// encoder retains whatever pending output byte(s) it hasn't emitted
+    /// yet, so calling `encode_slice` again with a fresh `output` picks up
+    /// right where it left off.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tick_encoding::encoder::Encoder;
+    ///
+    /// let mut encoder = Encoder::default();
+    /// let mut output = [0u8; 16];
+    /// let (consumed, written) = encoder.encode_slice(b"hi\x00", &mut output);
+    /// assert_eq!(consumed, 3);
+    /// assert_eq!(&output[..written], b"hi`00");
+    /// ```
+    #[inline]
+    pub fn encode_slice(&mut self, input: &[u8], output: &mut [u8]) -> (usize, usize) {
+        let mut consumed = 0;
+        let mut written = 0;
+
+        loop {
+            if written >= output.len() {
+                break;
+            }
+
+            if let Some(c) = self.next() {
+                output[written] = c as u8;
+                written += 1;
+                continue;
+            }
+
+            let Some(&byte) = input.get(consumed) else {
+                break;
+            };
+
+            output[written] = self.push(byte) as u8;
+            written += 1;
+            consumed += 1;
+        }
+
+        (consumed, written)
+    }
+
     #[inline]
     pub fn push(&mut self, byte: u8) -> char {
         if byte == b'`' {
@@ -54,3 +176,21 @@ impl Encoder {
         }
     }
 }
+
+/// A checkpoint of an [`Encoder`]'s progress through (or outside of) a
+/// pending escape, returned by [`Encoder::into_state`] and accepted by
+/// [`Encoder::from_state`]. This is a stable, purpose-built shape for
+/// persisting an encode across a process restart or async suspension point,
+/// decoupled from the encoder's actual internal representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EncoderState {
+    /// No characters pending; ready to encode the next byte.
+    Ready,
+    /// One escape character left to emit: the second backtick of a `` `` ``
+    /// escape for a literal `` ` ``, or the last hex digit of an escape
+    /// whose first hex digit was already emitted.
+    PendingChar(char),
+    /// Both hex digit characters of an escape left to emit, in order.
+    PendingHexDigits(char, char),
+}
@@ -0,0 +1,322 @@
+use std::io::{self, Read, Write};
+
+use crate::{
+    decoder::{DecodeStatus, Decoder},
+    encoder::Encoder,
+    DecodeError,
+};
+
+/// Wraps a [`Write`] sink, tick-encoding every byte passed to [`write`](Write::write)
+/// before forwarding the encoded text to the inner writer.
+///
+/// Bytes that require escaping (`` `XX ``) are always written to the inner
+/// writer as a whole, so a `write` call never leaves a half-written escape
+/// sequence behind.
+///
+/// ## Example
+///
+/// ```
+/// # #![cfg(feature = "std")]
+/// use std::io::Write;
+///
+/// let mut output = Vec::new();
+/// let mut writer = tick_encoding::io::EncodeWriter::new(&mut output);
+/// writer.write_all(&[b'h', b'i', 0x00]).unwrap();
+/// assert_eq!(output, b"hi`00");
+/// ```
+#[derive(Debug)]
+pub struct EncodeWriter<W> {
+    inner: W,
+    encoder: Encoder,
+    scratch: [u8; 3],
+}
+
+impl<W: Write> EncodeWriter<W> {
+    /// Wrap `inner`, encoding every byte written through this writer.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            encoder: Encoder::default(),
+            scratch: [0; 3],
+        }
+    }
+
+    /// Get a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap this `EncodeWriter`, returning the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for EncodeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for (written, &byte) in buf.iter().enumerate() {
+            let mut len = 0;
+            self.scratch[len] = self.encoder.push(byte) as u8;
+            len += 1;
+            while let Some(c) = self.encoder.next() {
+                self.scratch[len] = c as u8;
+                len += 1;
+            }
+
+            if let Err(err) = self.inner.write_all(&self.scratch[..len]) {
+                return if written == 0 { Err(err) } else { Ok(written) };
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Wraps a [`Read`] source of tick-encoded text, decoding it on the fly as
+/// bytes are pulled from [`read`](Read::read).
+///
+/// A `` ` `` escape that straddles two underlying `read` calls is handled
+/// correctly: the partially-seen escape is held internally until enough
+/// bytes have arrived to decode it.
+///
+/// ## Example
+///
+/// ```
+/// # #![cfg(feature = "std")]
+/// use std::io::Read;
+///
+/// let mut reader = tick_encoding::io::DecodeReader::new(&b"hi`00"[..]);
+/// let mut output = Vec::new();
+/// reader.read_to_end(&mut output).unwrap();
+/// assert_eq!(output, [b'h', b'i', 0x00]);
+/// ```
+#[derive(Debug)]
+pub struct DecodeReader<R> {
+    inner: R,
+    decoder: Decoder,
+    scratch: [u8; 1],
+}
+
+impl<R: Read> DecodeReader<R> {
+    /// Wrap `inner`, decoding the tick-encoded bytes read through it.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            decoder: Decoder::default(),
+            scratch: [0],
+        }
+    }
+
+    /// Get a reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwrap this `DecodeReader`, returning the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for DecodeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut written = 0;
+        while written < buf.len() {
+            let input = match self.inner.read(&mut self.scratch)? {
+                0 => None,
+                _ => Some(self.scratch[0]),
+            };
+
+            match self.decoder.push(input) {
+                DecodeStatus::NeedMore => continue,
+                DecodeStatus::Emit(None) => break,
+                DecodeStatus::Emit(Some(Ok(byte))) => {
+                    buf[written] = byte;
+                    written += 1;
+                }
+                DecodeStatus::Emit(Some(Err(err))) => {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, format!("{err:?}")));
+                }
+            }
+        }
+
+        Ok(written)
+    }
+}
+
+/// Wraps a [`Read`] source of raw bytes, tick-encoding them on the fly as
+/// the encoded text is pulled from [`read`](Read::read).
+///
+/// ## Example
+///
+/// ```
+/// # #![cfg(feature = "std")]
+/// use std::io::Read;
+///
+/// let mut reader = tick_encoding::io::EncodeReader::new(&[b'h', b'i', 0x00][..]);
+/// let mut output = Vec::new();
+/// reader.read_to_end(&mut output).unwrap();
+/// assert_eq!(output, b"hi`00");
+/// ```
+#[derive(Debug)]
+pub struct EncodeReader<R> {
+    inner: R,
+    encoder: Encoder,
+    scratch: [u8; 1],
+    done: bool,
+}
+
+impl<R: Read> EncodeReader<R> {
+    /// Wrap `inner`, encoding the raw bytes read through it.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            encoder: Encoder::default(),
+            scratch: [0],
+            done: false,
+        }
+    }
+
+    /// Get a reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwrap this `EncodeReader`, returning the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for EncodeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut written = 0;
+        while written < buf.len() {
+            if let Some(c) = self.encoder.next() {
+                buf[written] = c as u8;
+                written += 1;
+                continue;
+            }
+
+            if self.done {
+                break;
+            }
+
+            match self.inner.read(&mut self.scratch)? {
+                0 => self.done = true,
+                _ => {
+                    buf[written] = self.encoder.push(self.scratch[0]) as u8;
+                    written += 1;
+                }
+            }
+        }
+
+        Ok(written)
+    }
+}
+
+/// Wraps a [`Write`] sink of tick-encoded text, decoding it on the fly and
+/// forwarding the decoded bytes to the inner writer.
+///
+/// As with [`Decoder`], a `` ` `` escape that straddles two `write` calls is
+/// handled correctly: it's held internally until enough bytes have arrived
+/// to decode it. Call [`finish`](Self::finish) once all input has been
+/// written to check for a dangling escape at the end.
+///
+/// ## Example
+///
+/// ```
+/// # #![cfg(feature = "std")]
+/// use std::io::Write;
+///
+/// let mut output = Vec::new();
+/// let mut writer = tick_encoding::io::DecodeWriter::new(&mut output);
+/// writer.write_all(b"hi`00").unwrap();
+/// writer.finish().unwrap();
+/// assert_eq!(output, [b'h', b'i', 0x00]);
+/// ```
+#[derive(Debug)]
+pub struct DecodeWriter<W> {
+    inner: W,
+    decoder: Decoder,
+}
+
+impl<W: Write> DecodeWriter<W> {
+    /// Wrap `inner`, decoding the tick-encoded bytes written through it.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            decoder: Decoder::default(),
+        }
+    }
+
+    /// Get a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap this `DecodeWriter`, returning the inner writer without
+    /// checking for a dangling `` ` `` escape. Prefer [`finish`](Self::finish)
+    /// once all input has been written.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Finish decoding, returning [`DecodeError::UnexpectedEnd`] if a
+    /// `` ` `` escape was left incomplete, then return the inner writer.
+    pub fn finish(self) -> Result<W, DecodeError> {
+        self.decoder.finish()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for DecodeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut scratch = [0u8; 1];
+        for (written, &byte) in buf.iter().enumerate() {
+            match self.decoder.push(Some(byte)) {
+                DecodeStatus::NeedMore => continue,
+                DecodeStatus::Emit(None) => return Ok(written),
+                DecodeStatus::Emit(Some(Ok(decoded))) => {
+                    scratch[0] = decoded;
+                    if let Err(err) = self.inner.write_all(&scratch) {
+                        return if written == 0 { Err(err) } else { Ok(written) };
+                    }
+                }
+                DecodeStatus::Emit(Some(Err(err))) => {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, format!("{err:?}")));
+                }
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    decoder::{DecodeStatus, Decoder},
-    encoder::Encoder,
+    decoder::{DecodeOptions, DecodeStatus, Decoder, DecoderState},
+    encoder::{Encoder, EncoderState},
     DecodeError,
 };
 
@@ -33,6 +33,30 @@ impl<I> EncodeIter<I> {
     pub fn into_inner(self) -> I {
         self.iter
     }
+
+    /// Extract a checkpoint of this iterator's progress through (or outside
+    /// of) a pending escape, for persisting across a process restart or
+    /// async suspension point. Pair with [`from_state`](Self::from_state) to
+    /// resume encoding later from a fresh inner iterator picked up at the
+    /// same point in the stream.
+    ///
+    /// This only covers the encoder's own escape progress; as with
+    /// [`Encoder::into_state`], the caller is expected to persist (and
+    /// resume) the inner iterator separately.
+    pub fn into_state(self) -> EncodeIterState {
+        EncodeIterState {
+            encoder: self.encoder.into_state(),
+        }
+    }
+
+    /// Reconstruct an iterator from a checkpoint previously taken with
+    /// [`into_state`](Self::into_state), resuming over `iter`.
+    pub fn from_state(iter: I, state: EncodeIterState) -> Self {
+        Self {
+            iter,
+            encoder: Encoder::from_state(state.encoder),
+        }
+    }
 }
 
 impl<I> Iterator for EncodeIter<I>
@@ -52,11 +76,27 @@ where
     }
 }
 
+/// A checkpoint of an [`EncodeIter`]'s progress, returned by
+/// [`EncodeIter::into_state`] and accepted by [`EncodeIter::from_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EncodeIterState {
+    encoder: EncoderState,
+}
+
 /// The iterator returned by [`crate::decode_iter`].
 #[derive(Debug, Clone, Copy)]
 pub struct DecodeIter<I> {
     iter: I,
     decoder: Decoder,
+    /// Count of bytes consumed from `iter` so far.
+    offset: usize,
+    /// Offset of the byte that started the escape (or plain byte) currently
+    /// being decoded, i.e. what [`byte_offset`](Self::byte_offset) was just
+    /// before the decoder most recently left its "ready" state. Used to
+    /// point errors at the start of a `` ` `` escape instead of its final
+    /// hex digit.
+    escape_offset: usize,
 }
 
 impl<I> DecodeIter<I> {
@@ -64,6 +104,17 @@ impl<I> DecodeIter<I> {
         Self {
             iter,
             decoder: Decoder::default(),
+            offset: 0,
+            escape_offset: 0,
+        }
+    }
+
+    pub(crate) fn with_options(iter: I, options: DecodeOptions) -> Self {
+        Self {
+            iter,
+            decoder: Decoder::with_options(options),
+            offset: 0,
+            escape_offset: 0,
         }
     }
 
@@ -81,6 +132,109 @@ impl<I> DecodeIter<I> {
     pub fn into_inner(self) -> I {
         self.iter
     }
+
+    /// Returns the number of bytes repaired (replaced or skipped) so far
+    /// while recovering from malformed input. Always `0` unless
+    /// [`DecodeOptions::lossy`] is enabled.
+    pub fn repaired_count(&self) -> usize {
+        self.decoder.repaired_count()
+    }
+
+    /// Returns the number of bytes consumed from the inner iterator so far.
+    pub fn byte_offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Adapt this iterator so errors come with the byte offset of the
+    /// `` ` `` escape (or single byte) that produced them, instead of just
+    /// the bare error. Useful for producing caret-style error messages
+    /// against the original encoded text.
+    pub fn positioned(self) -> Positioned<I> {
+        Positioned { inner: self }
+    }
+
+    /// Extract a checkpoint of this iterator's progress through (or outside
+    /// of) a `` ` `` escape, for persisting across a process restart or
+    /// async suspension point. Pair with [`from_state`](Self::from_state) to
+    /// resume decoding later from a fresh inner iterator picked up at the
+    /// same point in the stream.
+    ///
+    /// This only covers the decoder's escape progress and this iterator's
+    /// own offset bookkeeping; as with [`Decoder::into_state`], the caller
+    /// is expected to persist [`DecodeOptions`] (and the inner iterator)
+    /// separately.
+    pub fn into_state(self) -> DecodeIterState {
+        DecodeIterState {
+            decoder: self.decoder.into_state(),
+            offset: self.offset,
+            escape_offset: self.escape_offset,
+        }
+    }
+
+    /// Reconstruct an iterator from a checkpoint previously taken with
+    /// [`into_state`](Self::into_state), resuming over `iter` with
+    /// `options`.
+    pub fn from_state(iter: I, state: DecodeIterState, options: DecodeOptions) -> Self {
+        Self {
+            iter,
+            decoder: Decoder::from_state(state.decoder, options),
+            offset: state.offset,
+            escape_offset: state.escape_offset,
+        }
+    }
+}
+
+impl<I> DecodeIter<I>
+where
+    I: Iterator<Item = u8>,
+{
+    /// Feed at most one byte from the inner iterator into the decoder,
+    /// without looping until a decoded byte is actually produced. Returns
+    /// `None` once the inner iterator is exhausted (and no repaired byte is
+    /// left queued up), after first returning
+    /// `Some(Err(DecodeError::UnexpectedEnd))` if it ran out in the middle of
+    /// a `` ` `` escape; otherwise mirrors [`Decoder::feed`], returning
+    /// `Ok(None)` while a byte was consumed but a `` ` `` escape is still in
+    /// progress.
+    ///
+    /// [`next`](Iterator::next) drains this in a loop until it produces a
+    /// decoded byte, which means the decoder is never observably mid-escape
+    /// in between two `next` calls. Use `step` directly to checkpoint at an
+    /// arbitrary point in the input instead, e.g. right after a `` ` `` or a
+    /// single hex digit, via [`into_state`](Self::into_state).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut iter = tick_encoding::decode_iter(b"`F".iter().copied());
+    /// assert_eq!(iter.step(), Some(Ok(None)));
+    /// assert_eq!(iter.step(), Some(Ok(None)));
+    /// assert_eq!(iter.byte_offset(), 2);
+    /// ```
+    pub fn step(&mut self) -> Option<Result<Option<u8>, DecodeError>> {
+        if let Some(queued) = self.decoder.pop_repaired() {
+            return Some(queued.map(Some));
+        }
+
+        let was_pending = self.decoder.is_pending();
+        let Some(byte) = self.iter.next() else {
+            // Let the decoder report an escape left dangling at the end
+            return match self.decoder.push(None) {
+                DecodeStatus::Emit(Some(result)) => Some(result.map(Some)),
+                DecodeStatus::NeedMore | DecodeStatus::Emit(None) => None,
+            };
+        };
+        if !was_pending {
+            self.escape_offset = self.offset;
+        }
+        self.offset += 1;
+
+        match self.decoder.push(Some(byte)) {
+            DecodeStatus::NeedMore => Some(Ok(None)),
+            DecodeStatus::Emit(Some(result)) => Some(result.map(Some)),
+            DecodeStatus::Emit(None) => None,
+        }
+    }
 }
 
 impl<I> Iterator for DecodeIter<I>
@@ -91,13 +245,58 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            let next_byte = self.iter.next();
-            match self.decoder.push(next_byte) {
-                DecodeStatus::NeedMore => {}
-                DecodeStatus::Emit(result) => {
-                    return result;
-                }
+            match self.step()? {
+                Ok(Some(byte)) => return Some(Ok(byte)),
+                Ok(None) => {}
+                Err(err) => return Some(Err(err)),
             }
         }
     }
 }
+
+/// A checkpoint of a [`DecodeIter`]'s progress, returned by
+/// [`DecodeIter::into_state`] and accepted by [`DecodeIter::from_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecodeIterState {
+    decoder: DecoderState,
+    offset: usize,
+    escape_offset: usize,
+}
+
+/// The iterator returned by [`DecodeIter::positioned`]. Like [`DecodeIter`],
+/// but each error comes paired with the byte offset of the `` ` `` escape
+/// (or single byte) that produced it.
+#[derive(Debug, Clone, Copy)]
+pub struct Positioned<I> {
+    inner: DecodeIter<I>,
+}
+
+impl<I> Positioned<I> {
+    /// Returns the number of bytes consumed from the inner iterator so far.
+    pub fn byte_offset(&self) -> usize {
+        self.inner.byte_offset()
+    }
+
+    /// Returns the number of bytes repaired (replaced or skipped) so far
+    /// while recovering from malformed input. Always `0` unless
+    /// [`DecodeOptions::lossy`] is enabled.
+    pub fn repaired_count(&self) -> usize {
+        self.inner.repaired_count()
+    }
+}
+
+impl<I> Iterator for Positioned<I>
+where
+    I: Iterator<Item = u8>,
+{
+    type Item = Result<u8, (usize, DecodeError)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.next() {
+            Some(Ok(byte)) => Some(Ok(byte)),
+            Some(Err(err)) => Some(Err((self.inner.escape_offset, err))),
+            None => None,
+        }
+    }
+}
@@ -2,9 +2,19 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "std", doc = include_str!("../README.md"))]
 
-pub(crate) mod decoder;
-pub(crate) mod encoder;
+pub mod decoder;
+pub mod encoder;
 pub mod iter;
+pub mod state;
+
+#[cfg(feature = "std")]
+pub mod io;
+
+#[cfg(feature = "bytes")]
+pub mod buf;
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod serde;
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -12,6 +22,8 @@ extern crate alloc;
 #[cfg(feature = "alloc")]
 use alloc::{borrow::Cow, string::String, vec::Vec};
 
+use decoder::DecodeOptions;
+
 /// Encode the given input as a string, escaping any bytes that require it.
 /// If no bytes require escaping, then the result will be borrowed from
 /// the input.
@@ -29,7 +41,7 @@ use alloc::{borrow::Cow, string::String, vec::Vec};
 #[cfg(feature = "alloc")]
 pub fn encode(input: &[u8]) -> Cow<str> {
     // Get the first index that needs to be escaped
-    let escape_index = input.iter().position(|byte| requires_escape(*byte));
+    let escape_index = next_escape_index(input);
 
     match escape_index {
         Some(index) => {
@@ -92,8 +104,26 @@ where
 /// ```
 #[cfg(feature = "alloc")]
 pub fn decode(input: &[u8]) -> Result<Cow<[u8]>, DecodeError> {
+    decode_with(input, &DecodeOptions::new())
+}
+
+/// Like [`decode`], but accepts non-canonical escapes permitted by
+/// `options` (see [`DecodeOptions`]) instead of erroring on them.
+///
+/// ## Example
+///
+/// ```
+/// # #![cfg(feature = "alloc")]
+/// use tick_encoding::decoder::DecodeOptions;
+///
+/// let options = DecodeOptions::new().lenient(true);
+/// let decoded = tick_encoding::decode_with(b"`65", &options).unwrap();
+/// assert_eq!(decoded, b"e".as_slice());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_with<'a>(input: &'a [u8], options: &DecodeOptions) -> Result<Cow<'a, [u8]>, DecodeError> {
     // Get the first index that isn't already a valid unescaped byte
-    let escape_index = input.iter().position(|byte| requires_escape(*byte));
+    let escape_index = next_escape_index(input);
 
     match escape_index {
         Some(index) => {
@@ -105,7 +135,7 @@ pub fn decode(input: &[u8]) -> Result<Cow<[u8]>, DecodeError> {
 
             // Decode the remainder of the input
             let requires_decoding = &input[index..];
-            decode_to_vec(requires_decoding, &mut output)?;
+            decode_to_vec_with(requires_decoding, &mut output, options)?;
             Ok(Cow::Owned(output))
         }
         None => Ok(Cow::Borrowed(input)),
@@ -129,6 +159,28 @@ where
     iter::DecodeIter::new(iter.into_iter())
 }
 
+/// Like [`decode_iter`], but accepts non-canonical escapes permitted by
+/// `options` (see [`DecodeOptions`]) instead of erroring on them.
+///
+/// ## Example
+///
+/// ```
+/// use tick_encoding::decoder::DecodeOptions;
+///
+/// let options = DecodeOptions::new().lenient(true);
+/// let iter = tick_encoding::decode_iter_with(b"`65`6c`6c`6F".iter().copied(), options);
+/// assert_eq!(
+///     iter.collect::<Result<Vec<_>, _>>().unwrap(),
+///     vec![b'e', b'l', b'l', b'o']
+/// );
+/// ```
+pub fn decode_iter_with<I>(iter: I, options: DecodeOptions) -> iter::DecodeIter<I::IntoIter>
+where
+    I: IntoIterator<Item = u8>,
+{
+    iter::DecodeIter::with_options(iter.into_iter(), options)
+}
+
 /// Take a byte slice containing a tick-encoded ASCII string, and decode it
 /// in-place, writing back into the same byte slice. Returns a sub-slice
 /// containing just the decoded bytes (the bytes past the returned sub-slice
@@ -142,8 +194,28 @@ where
 /// assert_eq!(decoded, b"bytes: \x00\x01\x02\x03");
 /// ```
 pub fn decode_in_place(input: &mut [u8]) -> Result<&mut [u8], DecodeError> {
+    decode_in_place_with(input, &DecodeOptions::new())
+}
+
+/// Like [`decode_in_place`], but accepts non-canonical escapes permitted by
+/// `options` (see [`DecodeOptions`]) instead of erroring on them.
+///
+/// ## Example
+///
+/// ```
+/// use tick_encoding::decoder::DecodeOptions;
+///
+/// let options = DecodeOptions::new().lenient(true);
+/// let mut buffer = b"bytes: `65`6c`6c`6F".to_vec();
+/// let decoded = tick_encoding::decode_in_place_with(&mut buffer, &options).unwrap();
+/// assert_eq!(decoded, b"bytes: ello");
+/// ```
+pub fn decode_in_place_with<'a>(
+    input: &'a mut [u8],
+    options: &DecodeOptions,
+) -> Result<&'a mut [u8], DecodeError> {
     // Get the first index that isn't already a valid unescaped byte
-    let Some(escape_index) = input.iter().position(|byte| requires_escape(*byte)) else {
+    let Some(escape_index) = next_escape_index(input) else {
         // Nothing needs to be unescaped
         return Ok(input);
     };
@@ -169,7 +241,7 @@ pub fn decode_in_place(input: &mut [u8]) -> Result<&mut [u8], DecodeError> {
                 }
                 high => {
                     let low = input.get(tail + 2).ok_or(DecodeError::UnexpectedEnd)?;
-                    let byte = hex_bytes_to_byte([*high, *low])?;
+                    let byte = hex_bytes_to_byte_with([*high, *low], options)?;
                     input[head] = byte;
                     tail += 3;
                     head += 1;
@@ -199,11 +271,94 @@ pub fn decode_in_place(input: &mut [u8]) -> Result<&mut [u8], DecodeError> {
 /// - Space (` `, 0x20)
 /// - Printable characters except bactick (0x21 to 0x59, 0x61 to 0x7E)
 pub fn requires_escape(byte: u8) -> bool {
-    match byte {
-        b'`' => true,
-        b'\t' | b'\n' | b'\r' | b' '..=b'~' => false,
-        _ => true,
+    CLASS[byte as usize] & REQUIRES_ESCAPE != 0
+}
+
+/// Bit set in a [`CLASS`] entry if the byte must be escaped (see
+/// [`requires_escape`]).
+const REQUIRES_ESCAPE: u8 = 0b0001_0000;
+/// Bit set in a [`CLASS`] entry if the byte is a hex digit (`[0-9A-Fa-f]`).
+const IS_HEX_DIGIT: u8 = 0b0010_0000;
+/// Bit set in a [`CLASS`] entry if the byte is a lowercase hex digit
+/// (`[a-f]`). Only meaningful if [`IS_HEX_DIGIT`] is also set.
+const IS_LOWERCASE_HEX: u8 = 0b0100_0000;
+/// Mask over the low nibble of a [`CLASS`] entry holding the byte's hex
+/// value. Only meaningful if [`IS_HEX_DIGIT`] is set.
+const HEX_VALUE_MASK: u8 = 0b0000_1111;
+
+/// A precomputed classification of every possible byte value, so the hot
+/// paths in this crate can replace a multi-arm match with a single indexed
+/// load. Each entry packs [`REQUIRES_ESCAPE`], [`IS_HEX_DIGIT`], and
+/// [`IS_LOWERCASE_HEX`] flags alongside the byte's hex value (in the low
+/// nibble, via [`HEX_VALUE_MASK`]).
+const CLASS: [u8; 256] = {
+    let mut table = [0u8; 256];
+    let mut byte = 0usize;
+    while byte < table.len() {
+        table[byte] = classify(byte as u8);
+        byte += 1;
     }
+    table
+};
+
+const fn classify(byte: u8) -> u8 {
+    let mut flags = match byte {
+        b'`' => REQUIRES_ESCAPE,
+        b'\t' | b'\n' | b'\r' | b' '..=b'~' => 0,
+        _ => REQUIRES_ESCAPE,
+    };
+
+    flags |= match byte {
+        b'0'..=b'9' => IS_HEX_DIGIT | (byte - b'0'),
+        b'A'..=b'F' => IS_HEX_DIGIT | (byte - b'A' + 10),
+        b'a'..=b'f' => IS_HEX_DIGIT | IS_LOWERCASE_HEX | (byte - b'a' + 10),
+        _ => 0,
+    };
+
+    flags
+}
+
+/// Find the index of the first byte in `bytes` that requires escaping (see
+/// [`requires_escape`]).
+///
+/// Unescaped text is almost always the common case, so this scans 8 bytes
+/// at a time, OR-reducing their [`CLASS`] bits into a single word to test
+/// them all at once, only falling back to a per-byte check when a word
+/// might contain a byte that needs escaping.
+fn next_escape_index(bytes: &[u8]) -> Option<usize> {
+    const ESCAPE_LANES: u64 = u64::from_ne_bytes([REQUIRES_ESCAPE; 8]);
+
+    let mut offset = 0;
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        let classes = [
+            CLASS[chunk[0] as usize],
+            CLASS[chunk[1] as usize],
+            CLASS[chunk[2] as usize],
+            CLASS[chunk[3] as usize],
+            CLASS[chunk[4] as usize],
+            CLASS[chunk[5] as usize],
+            CLASS[chunk[6] as usize],
+            CLASS[chunk[7] as usize],
+        ];
+
+        if u64::from_ne_bytes(classes) & ESCAPE_LANES != 0 {
+            if let Some(index) = classes
+                .iter()
+                .position(|class| class & REQUIRES_ESCAPE != 0)
+            {
+                return Some(offset + index);
+            }
+        }
+
+        offset += 8;
+    }
+
+    chunks
+        .remainder()
+        .iter()
+        .position(|byte| requires_escape(*byte))
+        .map(|index| offset + index)
 }
 
 /// Encode the given input, and append the result to `output`. Returns
@@ -258,21 +413,30 @@ pub fn encode_to_string(input: &[u8], output: &mut String) -> usize {
 pub fn encode_to_vec(input: &[u8], output: &mut Vec<u8>) -> usize {
     let mut written = 0;
     output.reserve(input.len());
-    for byte in input {
-        if *byte == b'`' {
+
+    let mut rest = input;
+    while let Some(index) = next_escape_index(rest) {
+        // Everything up to `index` is a run of unescaped bytes that can be
+        // copied through verbatim
+        output.extend_from_slice(&rest[..index]);
+        written += index;
+
+        let byte = rest[index];
+        if byte == b'`' {
             output.extend_from_slice(b"``");
             written += 2;
-        } else if requires_escape(*byte) {
-            let [high, low] = byte_to_hex_bytes(*byte);
+        } else {
+            let [high, low] = byte_to_hex_bytes(byte);
             output.extend_from_slice(&[b'`', high, low]);
-
             written += 3;
-        } else {
-            output.push(*byte);
-            written += 1;
         }
+
+        rest = &rest[index + 1..];
     }
 
+    output.extend_from_slice(rest);
+    written += rest.len();
+
     written
 }
 
@@ -292,6 +456,29 @@ pub fn encode_to_vec(input: &[u8], output: &mut Vec<u8>) -> usize {
 /// ```
 #[cfg(feature = "alloc")]
 pub fn decode_to_vec(input: &[u8], output: &mut Vec<u8>) -> Result<usize, DecodeError> {
+    decode_to_vec_with(input, output, &DecodeOptions::new())
+}
+
+/// Like [`decode_to_vec`], but accepts non-canonical escapes permitted by
+/// `options` (see [`DecodeOptions`]) instead of erroring on them.
+///
+/// ## Example
+///
+/// ```
+/// use tick_encoding::decoder::DecodeOptions;
+///
+/// let options = DecodeOptions::new().lenient(true);
+/// let mut output = vec![];
+/// let count = tick_encoding::decode_to_vec_with(b"`65`6c`6c`6F", &mut output, &options).unwrap();
+/// assert_eq!(output, b"ello");
+/// assert_eq!(count, 4);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_to_vec_with(
+    input: &[u8],
+    output: &mut Vec<u8>,
+    options: &DecodeOptions,
+) -> Result<usize, DecodeError> {
     let mut written = 0;
     let mut iter = input.iter();
     while let Some(byte) = iter.next() {
@@ -304,7 +491,7 @@ pub fn decode_to_vec(input: &[u8], output: &mut Vec<u8>) -> Result<usize, Decode
                 }
                 high => {
                     let low = iter.next().ok_or(DecodeError::UnexpectedEnd)?;
-                    let byte = hex_bytes_to_byte([*high, *low])?;
+                    let byte = hex_bytes_to_byte_with([*high, *low], options)?;
                     output.push(byte);
                     written += 1;
                 }
@@ -320,63 +507,294 @@ pub fn decode_to_vec(input: &[u8], output: &mut Vec<u8>) -> Result<usize, Decode
     Ok(written)
 }
 
-fn byte_to_hex_bytes(byte: u8) -> [u8; 2] {
-    let high = byte >> 4;
-    let low = byte & 0x0F;
+/// Repair a non-canonical tick-encoded string into its canonical form, by
+/// leniently decoding it (see [`DecodeOptions::lenient`]) and then
+/// re-encoding the result canonically. If `input` contains nothing that needs
+/// decoding, the result is borrowed from it; otherwise the re-encoded result
+/// is returned as a new owned buffer, even if it equals `input`.
+///
+/// ## Example
+///
+/// ```
+/// # #![cfg(feature = "alloc")]
+/// let canonical = tick_encoding::canonicalize(b"`65`6c`6c`6F").unwrap();
+/// assert_eq!(canonical, "ello".as_bytes());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn canonicalize(input: &[u8]) -> Result<Cow<[u8]>, DecodeError> {
+    if next_escape_index(input).is_none() {
+        // Nothing needs to be unescaped, so `input` is already canonical
+        return Ok(Cow::Borrowed(input));
+    }
 
-    let high_byte = match high {
-        0..=9 => b'0' + high,
-        10..=15 => b'A' + high - 10,
-        _ => unreachable!(),
-    };
-    let low_byte = match low {
-        0..=9 => b'0' + low,
-        10..=15 => b'A' + low - 10,
-        _ => unreachable!(),
-    };
+    let decoded = decode_with(input, &DecodeOptions::new().lenient(true))?;
 
-    [high_byte, low_byte]
+    let mut output = Vec::with_capacity(input.len());
+    encode_to_vec(&decoded, &mut output);
+    Ok(Cow::Owned(output))
 }
 
-fn byte_to_hex_chars(byte: u8) -> [char; 2] {
-    let [high_byte, low_byte] = byte_to_hex_bytes(byte);
-    [high_byte as char, low_byte as char]
+/// Like [`encode`], but passes valid non-ASCII UTF-8 scalar sequences
+/// through unescaped instead of hex-escaping every byte of them.
+///
+/// A byte `>= 0x80` is only passed through as part of a complete,
+/// non-overlong, non-surrogate UTF-8 sequence; a stray continuation byte, an
+/// overlong encoding, or a sequence left incomplete at the end of the input
+/// is still hex-escaped one byte at a time, so the result always round-trips
+/// through [`decode_utf8`] exactly.
+///
+/// ## Example
+///
+/// ```
+/// # #![cfg(feature = "alloc")]
+/// let encoded = tick_encoding::encode_utf8("hi 🙂".as_bytes());
+/// assert_eq!(encoded, "hi 🙂");
+///
+/// let encoded = tick_encoding::encode_utf8(&[0xF0, 0x9F]); // incomplete sequence
+/// assert_eq!(encoded, "`F0`9F");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_utf8(input: &[u8]) -> Cow<str> {
+    if let Ok(validated) = core::str::from_utf8(input) {
+        if validated
+            .bytes()
+            .all(|byte| byte >= 0x80 || !requires_escape(byte))
+        {
+            return Cow::Borrowed(validated);
+        }
+    }
+
+    let mut output = Vec::with_capacity(input.len());
+    encode_to_vec_utf8(input, &mut output);
+
+    // SAFETY: `encode_to_vec_utf8` only ever appends ASCII bytes, or whole
+    // byte sequences that were just validated as a single UTF-8 scalar, so
+    // the result is always valid UTF-8.
+    debug_assert!(core::str::from_utf8(&output).is_ok());
+    Cow::Owned(string_from_utf8_unchecked_potentially_unsafe(output))
 }
 
-fn hex_bytes_to_byte([high, low]: [u8; 2]) -> Result<u8, DecodeError> {
-    enum HexCharResult {
-        Valid(u8),
-        Lowercase(char),
-        Invalid(char),
+/// Decode the given input, reversing [`encode_utf8`]. If no bytes need to
+/// be un-escaped, then the result will be borrowed from the input.
+///
+/// Unlike [`decode`], a byte `>= 0x80` is expected to begin a raw UTF-8
+/// sequence rather than always being an error; `` ` `` still introduces a
+/// hex or literal-backtick escape as usual.
+///
+/// ## Example
+///
+/// ```
+/// # #![cfg(feature = "alloc")]
+/// let decoded = tick_encoding::decode_utf8("hi 🙂".as_bytes()).unwrap();
+/// assert_eq!(decoded, "hi 🙂".as_bytes());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_utf8(input: &[u8]) -> Result<Cow<[u8]>, DecodeError> {
+    match input.iter().position(|&byte| byte == b'`') {
+        Some(index) => {
+            let validated = &input[..index];
+            validate_utf8_passthrough(validated)?;
+
+            let mut output = Vec::with_capacity(input.len());
+            output.extend_from_slice(validated);
+            decode_to_vec_utf8(&input[index..], &mut output)?;
+            Ok(Cow::Owned(output))
+        }
+        None => {
+            validate_utf8_passthrough(input)?;
+            Ok(Cow::Borrowed(input))
+        }
     }
+}
 
-    let high_value = match high {
-        b'0'..=b'9' => HexCharResult::Valid(high - b'0'),
-        b'A'..=b'F' => HexCharResult::Valid(high - b'A' + 10),
-        b'a'..=b'f' => HexCharResult::Lowercase(high as char),
-        _ => HexCharResult::Invalid(high as char),
-    };
+/// Encode the given input using [`encode_utf8`]'s rules, and append the
+/// result to `output`. Returns the number of bytes appended.
+///
+/// ## Example
+///
+/// ```
+/// let mut output = vec![];
+/// let count = tick_encoding::encode_to_vec_utf8("hi 🙂".as_bytes(), &mut output);
+/// assert_eq!(output, "hi 🙂".as_bytes());
+/// assert_eq!(count, 7);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_to_vec_utf8(input: &[u8], output: &mut Vec<u8>) -> usize {
+    let mut written = 0;
+    output.reserve(input.len());
 
-    let low_value = match low {
-        b'0'..=b'9' => HexCharResult::Valid(low - b'0'),
-        b'A'..=b'F' => HexCharResult::Valid(low - b'A' + 10),
-        b'a'..=b'f' => HexCharResult::Lowercase(low as char),
-        _ => HexCharResult::Invalid(low as char),
-    };
+    let mut rest = input;
+    while let Some(&byte) = rest.first() {
+        if byte < 0x80 {
+            if byte == b'`' {
+                output.extend_from_slice(b"``");
+                written += 2;
+            } else if requires_escape(byte) {
+                let [high, low] = byte_to_hex_bytes(byte);
+                output.extend_from_slice(&[b'`', high, low]);
+                written += 3;
+            } else {
+                output.push(byte);
+                written += 1;
+            }
 
-    let byte = match (high_value, low_value) {
-        (HexCharResult::Valid(high_value), HexCharResult::Valid(low_value)) => {
-            (high_value << 4) | low_value
+            rest = &rest[1..];
+            continue;
         }
-        (HexCharResult::Invalid(_), _) | (_, HexCharResult::Invalid(_)) => {
-            return Err(DecodeError::InvalidHex(EscapedHex(high, low)));
+
+        match utf8_scalar_at(rest) {
+            Some(scalar) => {
+                output.extend_from_slice(scalar);
+                written += scalar.len();
+                rest = &rest[scalar.len()..];
+            }
+            None => {
+                let [high, low] = byte_to_hex_bytes(byte);
+                output.extend_from_slice(&[b'`', high, low]);
+                written += 3;
+                rest = &rest[1..];
+            }
         }
-        (HexCharResult::Lowercase(_), _) | (_, HexCharResult::Lowercase(_)) => {
-            return Err(DecodeError::LowercaseHex(EscapedHex(high, low)));
+    }
+
+    written
+}
+
+/// Decode the given tick-encoded input using [`decode_utf8`]'s rules, and
+/// append the result to `output`. Returns the number of bytes appended.
+///
+/// ## Example
+///
+/// ```
+/// let mut output = vec![];
+/// let count = tick_encoding::decode_to_vec_utf8("hi 🙂".as_bytes(), &mut output).unwrap();
+/// assert_eq!(output, "hi 🙂".as_bytes());
+/// assert_eq!(count, 7);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_to_vec_utf8(input: &[u8], output: &mut Vec<u8>) -> Result<usize, DecodeError> {
+    let mut written = 0;
+    let mut rest = input;
+    while let Some(&byte) = rest.first() {
+        if byte == b'`' {
+            let escaped = rest.get(1).ok_or(DecodeError::UnexpectedEnd)?;
+            match escaped {
+                b'`' => {
+                    output.push(b'`');
+                    written += 1;
+                    rest = &rest[2..];
+                }
+                high => {
+                    let low = rest.get(2).ok_or(DecodeError::UnexpectedEnd)?;
+                    let byte = hex_bytes_to_byte([*high, *low])?;
+                    output.push(byte);
+                    written += 1;
+                    rest = &rest[3..];
+                }
+            }
+        } else if byte < 0x80 {
+            if requires_escape(byte) {
+                return Err(DecodeError::InvalidByte(byte));
+            }
+            output.push(byte);
+            written += 1;
+            rest = &rest[1..];
+        } else {
+            let scalar = utf8_scalar_at(rest).ok_or(DecodeError::InvalidByte(byte))?;
+            output.extend_from_slice(scalar);
+            written += scalar.len();
+            rest = &rest[scalar.len()..];
         }
-    };
+    }
+
+    Ok(written)
+}
+
+/// Attempt to decode one complete, non-overlong, non-surrogate UTF-8 scalar
+/// sequence starting at the beginning of `bytes` (whose first byte must be
+/// `>= 0x80`). Returns the sub-slice containing just that sequence.
+#[cfg(feature = "alloc")]
+fn utf8_scalar_at(bytes: &[u8]) -> Option<&[u8]> {
+    let len = utf8_sequence_len(*bytes.first()?)?;
+    let scalar = bytes.get(..len)?;
+    core::str::from_utf8(scalar).ok()?;
+    Some(scalar)
+}
+
+/// Returns the expected length in bytes of a UTF-8 sequence starting with
+/// `lead_byte`, or `None` if `lead_byte` can't begin a multi-byte sequence
+/// (a stray continuation byte, or a lead byte that's always invalid).
+#[cfg(feature = "alloc")]
+fn utf8_sequence_len(lead_byte: u8) -> Option<usize> {
+    match lead_byte {
+        0xC2..=0xDF => Some(2),
+        0xE0..=0xEF => Some(3),
+        0xF0..=0xF4 => Some(4),
+        _ => None,
+    }
+}
+
+/// Validate that every byte in `bytes` is either an unescaped ASCII byte
+/// that doesn't require escaping, or part of a complete UTF-8 scalar
+/// sequence, per [`encode_utf8`]'s rules.
+#[cfg(feature = "alloc")]
+fn validate_utf8_passthrough(bytes: &[u8]) -> Result<(), DecodeError> {
+    let mut rest = bytes;
+    while let Some(&byte) = rest.first() {
+        if byte < 0x80 {
+            if requires_escape(byte) {
+                return Err(DecodeError::InvalidByte(byte));
+            }
+            rest = &rest[1..];
+        } else {
+            let scalar = utf8_scalar_at(rest).ok_or(DecodeError::InvalidByte(byte))?;
+            rest = &rest[scalar.len()..];
+        }
+    }
 
-    if byte == b'`' || !requires_escape(byte) {
+    Ok(())
+}
+
+pub(crate) fn nibble_to_hex(nibble: u8) -> u8 {
+    match nibble {
+        0..=9 => b'0' + nibble,
+        10..=15 => b'A' + nibble - 10,
+        _ => unreachable!(),
+    }
+}
+
+fn byte_to_hex_bytes(byte: u8) -> [u8; 2] {
+    [nibble_to_hex(byte >> 4), nibble_to_hex(byte & 0x0F)]
+}
+
+fn byte_to_hex_chars(byte: u8) -> [char; 2] {
+    let [high_byte, low_byte] = byte_to_hex_bytes(byte);
+    [high_byte as char, low_byte as char]
+}
+
+fn hex_bytes_to_byte(hex: [u8; 2]) -> Result<u8, DecodeError> {
+    hex_bytes_to_byte_with(hex, &DecodeOptions::new())
+}
+
+pub(crate) fn hex_bytes_to_byte_with(
+    [high, low]: [u8; 2],
+    options: &DecodeOptions,
+) -> Result<u8, DecodeError> {
+    let high_class = CLASS[high as usize];
+    let low_class = CLASS[low as usize];
+
+    if high_class & IS_HEX_DIGIT == 0 || low_class & IS_HEX_DIGIT == 0 {
+        return Err(DecodeError::InvalidHex(EscapedHex(high, low)));
+    }
+    if !options.is_lenient()
+        && (high_class & IS_LOWERCASE_HEX != 0 || low_class & IS_LOWERCASE_HEX != 0)
+    {
+        return Err(DecodeError::LowercaseHex(EscapedHex(high, low)));
+    }
+
+    let byte = ((high_class & HEX_VALUE_MASK) << 4) | (low_class & HEX_VALUE_MASK);
+
+    if !options.is_lenient() && (byte == b'`' || !requires_escape(byte)) {
         return Err(DecodeError::UnexpectedEscape(
             EscapedHex(high, low),
             byte as char,
@@ -397,6 +815,17 @@ fn from_utf8_unchecked_potentially_unsafe(bytes: &[u8]) -> &str {
     unsafe { core::str::from_utf8_unchecked(bytes) }
 }
 
+#[cfg(all(feature = "alloc", feature = "safe"))]
+fn string_from_utf8_unchecked_potentially_unsafe(bytes: Vec<u8>) -> String {
+    String::from_utf8(bytes).unwrap()
+}
+
+#[cfg(all(feature = "alloc", not(feature = "safe")))]
+fn string_from_utf8_unchecked_potentially_unsafe(bytes: Vec<u8>) -> String {
+    debug_assert!(core::str::from_utf8(&bytes).is_ok());
+    unsafe { String::from_utf8_unchecked(bytes) }
+}
+
 /// An error trying to decode a tick-encoded string.
 #[derive(Debug)]
 #[cfg_attr(feature = "dep:thiserror", derive(thiserror::Error))]
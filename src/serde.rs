@@ -0,0 +1,76 @@
+//! Serde support for embedding tick-encoded byte strings in other data
+//! formats (JSON, TOML, YAML, ...).
+
+use alloc::{format, string::String, vec::Vec};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{decode, encode};
+
+/// `serialize_with`/`deserialize_with` helpers that encode a `&[u8]` field
+/// as a tick-encoded string.
+///
+/// ## Example
+///
+/// ```
+/// # #![cfg(all(feature = "serde", feature = "alloc"))]
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Message {
+///     #[serde(with = "tick_encoding::serde::as_string")]
+///     payload: Vec<u8>,
+/// }
+/// ```
+pub mod as_string {
+    use super::*;
+
+    /// Serialize `bytes` as a tick-encoded string.
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&encode(bytes))
+    }
+
+    /// Deserialize a tick-encoded string into its decoded bytes.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        decode(string.as_bytes())
+            .map(|bytes| bytes.into_owned())
+            .map_err(|err| D::Error::custom(format!("{err:?}")))
+    }
+}
+
+/// A byte buffer that serializes as a tick-encoded string, and decodes back
+/// from one.
+///
+/// ## Example
+///
+/// ```
+/// # #![cfg(all(feature = "serde", feature = "alloc"))]
+/// use tick_encoding::serde::TickEncoded;
+///
+/// let json = serde_json::to_string(&TickEncoded(vec![0x00, 0xFF])).unwrap();
+/// assert_eq!(json, "\"`00`FF\"");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TickEncoded(pub Vec<u8>);
+
+impl Serialize for TickEncoded {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        as_string::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TickEncoded {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        as_string::deserialize(deserializer).map(TickEncoded)
+    }
+}
@@ -0,0 +1,114 @@
+//! Push-style, allocation-free encoder/decoder state machines for pumping
+//! data through fixed-size buffers a chunk at a time (e.g. in `no_std`
+//! environments without `alloc`, or other bounded-memory streaming
+//! contexts).
+
+use crate::{decoder::Decoder, encoder::Encoder, DecodeError};
+
+/// The result of a single [`TickEncoderState::step`] or
+/// [`TickDecoderState::step`] call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// The number of bytes consumed from the input.
+    pub consumed: usize,
+    /// The number of bytes written to the output.
+    pub produced: usize,
+    /// Whether all of the input was consumed and the state machine is not
+    /// left in the middle of an escape sequence. This does not by itself mean the overall stream has
+    /// ended; it just means it's currently safe to stop feeding input
+    /// without truncating a `` ` `` escape.
+    pub done: bool,
+}
+
+/// A push-style encoder state machine that encodes `input` into `output`
+/// one [`step`](Self::step) at a time, without requiring an allocator.
+///
+/// ## Example
+///
+/// ```
+/// use tick_encoding::state::TickEncoderState;
+///
+/// let mut state = TickEncoderState::default();
+/// let mut output = [0u8; 8];
+/// let progress = state.step(&[0x00, 0xFF], &mut output).unwrap();
+/// assert_eq!(&output[..progress.produced], b"`00`FF");
+/// assert_eq!(progress.consumed, 2);
+/// assert!(progress.done);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TickEncoderState {
+    encoder: Encoder,
+}
+
+impl TickEncoderState {
+    /// Encode as much of `input` as fits into `output`, returning how many
+    /// bytes of each were used.
+    ///
+    /// This is a thin wrapper over [`Encoder::encode_slice`], reporting
+    /// progress in the shared [`Progress`] shape instead of a bare
+    /// `(usize, usize)` tuple.
+    pub fn step(&mut self, input: &[u8], output: &mut [u8]) -> Result<Progress, DecodeError> {
+        let (consumed, produced) = self.encoder.encode_slice(input, output);
+        let done = consumed == input.len() && !self.encoder.has_pending();
+        Ok(Progress {
+            consumed,
+            produced,
+            done,
+        })
+    }
+}
+
+/// A push-style decoder state machine that decodes `input` into `output`
+/// one [`step`](Self::step) at a time, without requiring an allocator.
+///
+/// A half-finished `` `X `` escape that falls on a chunk boundary is
+/// carried across calls automatically. Once the caller has no more input to
+/// provide, call `step` with an empty `input` slice: this reports
+/// [`DecodeError::UnexpectedEnd`] if a `` ` `` escape was left incomplete,
+/// matching the semantics of [`crate::decode`].
+///
+/// ## Example
+///
+/// ```
+/// use tick_encoding::state::TickDecoderState;
+///
+/// let mut state = TickDecoderState::default();
+/// let mut output = [0u8; 8];
+///
+/// // A `` ` `` escape split across two `step` calls is still decoded
+/// // correctly.
+/// let progress = state.step(b"hi`0", &mut output).unwrap();
+/// assert_eq!(&output[..progress.produced], b"hi");
+///
+/// let progress = state.step(b"0", &mut output).unwrap();
+/// assert_eq!(&output[..progress.produced], b"\x00");
+///
+/// let progress = state.step(b"", &mut output).unwrap();
+/// assert!(progress.done);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TickDecoderState {
+    decoder: Decoder,
+}
+
+impl TickDecoderState {
+    /// Decode as much of `input` as fits into `output`, returning how many
+    /// bytes of each were used.
+    ///
+    /// Call with an empty `input` slice once the stream has truly ended, to
+    /// check for (and surface) a dangling escape sequence.
+    ///
+    /// This is a thin wrapper over [`Decoder::decode_slice`] (treating an
+    /// empty `input` as the `eof` signal), reporting progress in the shared
+    /// [`Progress`] shape instead of a bare `(usize, usize)` tuple.
+    pub fn step(&mut self, input: &[u8], output: &mut [u8]) -> Result<Progress, DecodeError> {
+        let eof = input.is_empty();
+        let (consumed, produced) = self.decoder.decode_slice(input, output, eof)?;
+        let done = consumed == input.len() && !self.decoder.is_pending();
+        Ok(Progress {
+            consumed,
+            produced,
+            done,
+        })
+    }
+}
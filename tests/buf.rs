@@ -0,0 +1,50 @@
+#![cfg(feature = "bytes")]
+
+use bytes::BytesMut;
+use tick_encoding::buf::{decode_from_buf, encode_to_buf};
+
+#[test]
+fn test_encode_to_buf() {
+    let mut out = BytesMut::new();
+    let count = encode_to_buf(b"hello world!", &mut out);
+    assert_eq!(out, &b"hello world!"[..]);
+    assert_eq!(count, 12);
+
+    let mut out = BytesMut::new();
+    let count = encode_to_buf(&[0x00, 0xFF], &mut out);
+    assert_eq!(out, &b"`00`FF"[..]);
+    assert_eq!(count, 6);
+}
+
+#[test]
+fn test_decode_from_buf() {
+    let mut input = &b"hello world!"[..];
+    let mut out = BytesMut::new();
+    let count = decode_from_buf(&mut input, &mut out).unwrap();
+    assert_eq!(out, &b"hello world!"[..]);
+    assert_eq!(count, 12);
+
+    let mut input = &b"`00`FF"[..];
+    let mut out = BytesMut::new();
+    let count = decode_from_buf(&mut input, &mut out).unwrap();
+    assert_eq!(out, &[0x00, 0xFF][..]);
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_decode_from_buf_across_chunks() {
+    use bytes::Buf;
+
+    let mut input = (&b"ok `0"[..]).chain(&b"0`FF"[..]);
+    let mut out = BytesMut::new();
+    let count = decode_from_buf(&mut input, &mut out).unwrap();
+    assert_eq!(out, &b"ok \x00\xFF"[..]);
+    assert_eq!(count, 5);
+}
+
+#[test]
+fn test_decode_from_buf_invalid_byte_error() {
+    let mut input = &[0xFF][..];
+    let mut out = BytesMut::new();
+    assert!(decode_from_buf(&mut input, &mut out).is_err());
+}
@@ -100,3 +100,90 @@ fn test_decode_unexpected_escape_error() {
         Err(DecodeError::UnexpectedEscape(EscapedHex(b'6', b'5'), 'e'))
     );
 }
+
+#[test]
+fn test_byte_offset_tracks_consumed_bytes() {
+    let mut iter = tick_encoding::decode_iter(b"ab`FF".iter().copied());
+    assert_eq!(iter.byte_offset(), 0);
+    assert_matches!(iter.next(), Some(Ok(b'a')));
+    assert_eq!(iter.byte_offset(), 1);
+    assert_matches!(iter.next(), Some(Ok(b'b')));
+    assert_eq!(iter.byte_offset(), 2);
+    assert_matches!(iter.next(), Some(Ok(0xFF)));
+    assert_eq!(iter.byte_offset(), 5);
+}
+
+#[test]
+fn test_positioned_reports_offset_of_invalid_byte() {
+    let mut iter = tick_encoding::decode_iter([b'a', 0xFF].iter().copied()).positioned();
+    assert_matches!(iter.next(), Some(Ok(b'a')));
+    assert_matches!(
+        iter.next(),
+        Some(Err((1, DecodeError::InvalidByte(0xFF))))
+    );
+}
+
+#[test]
+fn test_positioned_reports_start_of_broken_escape_not_final_hex_digit() {
+    // The escape starts at offset 2 (the `` ` ``); the invalid hex digit
+    // that actually trips the error is at offset 4.
+    let mut iter = tick_encoding::decode_iter(b"ab`FG".iter().copied()).positioned();
+    assert_matches!(iter.next(), Some(Ok(b'a')));
+    assert_matches!(iter.next(), Some(Ok(b'b')));
+    assert_matches!(
+        iter.next(),
+        Some(Err((2, DecodeError::InvalidHex(_))))
+    );
+}
+
+#[test]
+fn test_positioned_reports_start_of_escape_for_unexpected_end() {
+    let mut iter = tick_encoding::decode_iter(b"ab`F".iter().copied()).positioned();
+    assert_matches!(iter.next(), Some(Ok(b'a')));
+    assert_matches!(iter.next(), Some(Ok(b'b')));
+    assert_matches!(iter.next(), Some(Err((2, DecodeError::UnexpectedEnd))));
+}
+
+#[test]
+fn test_checkpoint_round_trip_across_split_escape() {
+    use tick_encoding::decoder::DecodeOptions;
+
+    let mut iter = tick_encoding::decode_iter(b"ab`F".iter().copied());
+    assert_matches!(iter.next(), Some(Ok(b'a')));
+    assert_matches!(iter.next(), Some(Ok(b'b')));
+    // `next` drains until a byte is decoded, so it can never leave the
+    // decoder observably mid-escape; use `step` to stop right after the
+    // `` ` `` and the first hex digit instead.
+    assert_matches!(iter.step(), Some(Ok(None)));
+    assert_matches!(iter.step(), Some(Ok(None)));
+
+    // Checkpoint mid-escape, as if the process were restarting here, then
+    // resume over a fresh iterator holding the rest of the stream.
+    let state = iter.into_state();
+    let mut iter =
+        tick_encoding::iter::DecodeIter::from_state(b"F".iter().copied(), state, DecodeOptions::new());
+    assert_matches!(iter.next(), Some(Ok(0xFF)));
+    assert_matches!(iter.next(), None);
+}
+
+#[cfg(feature = "serde")]
+mod serde_derive {
+    use super::*;
+    use tick_encoding::decoder::DecodeOptions;
+    use tick_encoding::iter::DecodeIter;
+
+    #[test]
+    fn test_decode_iter_state_serializes_mid_escape() {
+        let mut iter = tick_encoding::decode_iter(b"ab`F".iter().copied());
+        assert_matches!(iter.next(), Some(Ok(b'a')));
+        assert_matches!(iter.next(), Some(Ok(b'b')));
+        assert_matches!(iter.step(), Some(Ok(None)));
+        assert_matches!(iter.step(), Some(Ok(None)));
+
+        let json = serde_json::to_string(&iter.into_state()).unwrap();
+        let state = serde_json::from_str(&json).unwrap();
+        let mut iter = DecodeIter::from_state(b"F".iter().copied(), state, DecodeOptions::new());
+        assert_matches!(iter.next(), Some(Ok(0xFF)));
+        assert_matches!(iter.next(), None);
+    }
+}
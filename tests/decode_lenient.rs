@@ -0,0 +1,92 @@
+#![cfg(feature = "alloc")]
+
+use assert_matches::assert_matches;
+use tick_encoding::decoder::DecodeOptions;
+use tick_encoding::{
+    canonicalize, decode_in_place_with, decode_iter_with, decode_to_vec_with, decode_with,
+    DecodeError,
+};
+
+#[test]
+fn test_decode_with_strict_matches_decode() {
+    let options = DecodeOptions::new();
+    assert_eq!(decode_with(b"hello", &options).unwrap(), &b"hello"[..]);
+    assert_eq!(decode_with(b"`FF", &options).unwrap(), &[0xFF][..]);
+    assert_matches!(
+        decode_with(b"`fe", &options),
+        Err(DecodeError::LowercaseHex(_))
+    );
+    assert_matches!(
+        decode_with(b"`65", &options),
+        Err(DecodeError::UnexpectedEscape(_, _))
+    );
+}
+
+#[test]
+fn test_decode_with_lenient_accepts_lowercase_hex() {
+    let options = DecodeOptions::new().lenient(true);
+    assert_eq!(decode_with(b"`ff", &options).unwrap(), &[0xFF][..]);
+    assert_eq!(decode_with(b"`Ff", &options).unwrap(), &[0xFF][..]);
+}
+
+#[test]
+fn test_decode_with_lenient_accepts_redundant_escapes() {
+    let options = DecodeOptions::new().lenient(true);
+    assert_eq!(decode_with(b"`65`6c`6c`6F", &options).unwrap(), &b"ello"[..]);
+}
+
+#[test]
+fn test_decode_with_lenient_still_rejects_invalid_byte_and_hex() {
+    let options = DecodeOptions::new().lenient(true);
+    assert_matches!(
+        decode_with(&[0xFF], &options),
+        Err(DecodeError::InvalidByte(0xFF))
+    );
+    assert_matches!(
+        decode_with(b"`GE", &options),
+        Err(DecodeError::InvalidHex(_))
+    );
+    assert_matches!(
+        decode_with(b"`F", &options),
+        Err(DecodeError::UnexpectedEnd)
+    );
+}
+
+#[test]
+fn test_decode_in_place_with_lenient() {
+    let options = DecodeOptions::new().lenient(true);
+    let mut buffer = b"bytes: `65`6c`6c`6F".to_vec();
+    let decoded = decode_in_place_with(&mut buffer, &options).unwrap();
+    assert_eq!(decoded, b"bytes: ello");
+}
+
+#[test]
+fn test_decode_to_vec_with_lenient() {
+    let options = DecodeOptions::new().lenient(true);
+    let mut output = vec![];
+    let count = decode_to_vec_with(b"`65`6c`6c`6F", &mut output, &options).unwrap();
+    assert_eq!(output, b"ello");
+    assert_eq!(count, 4);
+}
+
+#[test]
+fn test_decode_iter_with_lenient() {
+    let options = DecodeOptions::new().lenient(true);
+    let iter = decode_iter_with(b"`65`6c`6c`6F".iter().copied(), options);
+    assert_eq!(
+        iter.collect::<Result<Vec<_>, _>>().unwrap(),
+        vec![b'e', b'l', b'l', b'o']
+    );
+}
+
+#[test]
+fn test_canonicalize() {
+    assert_eq!(canonicalize(b"`65`6c`6c`6F").unwrap(), &b"ello"[..]);
+    assert_eq!(canonicalize(b"`FF`00").unwrap(), &b"`FF`00"[..]);
+    assert_eq!(canonicalize(b"hello world!").unwrap(), &b"hello world!"[..]);
+}
+
+#[test]
+fn test_canonicalize_still_errors_on_invalid_input() {
+    assert_matches!(canonicalize(&[0x00]), Err(DecodeError::InvalidByte(0x00)));
+}
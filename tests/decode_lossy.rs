@@ -0,0 +1,111 @@
+use assert_matches::assert_matches;
+use tick_encoding::decoder::{DecodeOptions, Decoder};
+use tick_encoding::{decode_iter_with, DecodeError};
+
+#[test]
+fn test_feed_invalid_byte_passthrough() {
+    let options = DecodeOptions::new().lossy(true);
+    let mut decoder = Decoder::with_options(options);
+    assert_eq!(decoder.feed(0xFF).unwrap(), Some(0xFF));
+    assert_eq!(decoder.feed(b'x').unwrap(), Some(b'x'));
+    decoder.finish().unwrap();
+    assert_eq!(decoder.repaired_count(), 1);
+}
+
+#[test]
+fn test_feed_invalid_byte_replacement() {
+    let options = DecodeOptions::new().lossy(true).replacement(b'?');
+    let mut decoder = Decoder::with_options(options);
+    assert_eq!(decoder.feed(0xFF).unwrap(), Some(b'?'));
+    assert_eq!(decoder.repaired_count(), 1);
+}
+
+#[test]
+fn test_feed_invalid_hex_skips_and_reexamines_low() {
+    // `` `0Z `` has an invalid hex digit in `Z`, but `Z` on its own is a
+    // perfectly ordinary byte, so it's emitted once the broken escape is
+    // skipped instead of being swallowed.
+    let options = DecodeOptions::new().lossy(true);
+    let mut decoder = Decoder::with_options(options);
+    assert_eq!(decoder.feed(b'`').unwrap(), None);
+    assert_eq!(decoder.feed(b'0').unwrap(), None);
+    assert_eq!(decoder.feed(b'Z').unwrap(), Some(b'Z'));
+    assert_eq!(decoder.repaired_count(), 1);
+}
+
+#[test]
+fn test_feed_invalid_hex_with_replacement_queues_reexamined_byte() {
+    let options = DecodeOptions::new().lossy(true).replacement(b'?');
+    let mut decoder = Decoder::with_options(options);
+    assert_eq!(decoder.feed(b'`').unwrap(), None);
+    assert_eq!(decoder.feed(b'0').unwrap(), None);
+    assert_eq!(decoder.feed(b'Z').unwrap(), Some(b'?'));
+    assert_matches!(decoder.pop_repaired(), Some(Ok(b'Z')));
+    assert_matches!(decoder.pop_repaired(), None);
+}
+
+#[test]
+fn test_feed_stray_backtick_after_broken_escape_starts_new_escape() {
+    // The broken escape is skipped, but the stray backtick right after it
+    // should still open a new escape instead of being dropped.
+    let options = DecodeOptions::new().lossy(true);
+    let mut decoder = Decoder::with_options(options);
+    assert_eq!(decoder.feed(b'`').unwrap(), None);
+    assert_eq!(decoder.feed(b'0').unwrap(), None);
+    assert_eq!(decoder.feed(b'`').unwrap(), None);
+    assert_eq!(decoder.feed(b'F').unwrap(), None);
+    assert_eq!(decoder.feed(b'F').unwrap(), Some(0xFF));
+    assert_eq!(decoder.repaired_count(), 1);
+}
+
+#[test]
+fn test_finish_mid_escape_is_ok_when_lossy() {
+    let options = DecodeOptions::new().lossy(true);
+    let mut decoder = Decoder::with_options(options);
+    assert_eq!(decoder.feed(b'`').unwrap(), None);
+    decoder.finish().unwrap();
+
+    let mut decoder = Decoder::with_options(options);
+    assert_eq!(decoder.feed(b'`').unwrap(), None);
+    assert_eq!(decoder.feed(b'F').unwrap(), None);
+    decoder.finish().unwrap();
+}
+
+#[test]
+fn test_decode_slice_recovers_from_invalid_byte() {
+    let options = DecodeOptions::new().lossy(true).replacement(b'?');
+    let mut decoder = Decoder::with_options(options);
+    let mut output = [0u8; 16];
+    let (consumed, written) = decoder
+        .decode_slice(b"a\xFFb", &mut output, true)
+        .unwrap();
+    assert_eq!(consumed, 3);
+    assert_eq!(&output[..written], b"a?b");
+}
+
+#[test]
+fn test_decode_iter_with_lossy_reports_repaired_count() {
+    // `\xFF` is repaired as a standalone invalid byte; `` `0Z `` is a broken
+    // escape whose `Z` low nibble gets re-examined as its own `Ready` byte.
+    let options = DecodeOptions::new().lossy(true).replacement(b'?');
+    let mut iter = decode_iter_with(b"a\xFFb`0Zc".iter().copied(), options);
+    assert_matches!(iter.next(), Some(Ok(b'a')));
+    assert_matches!(iter.next(), Some(Ok(b'?')));
+    assert_matches!(iter.next(), Some(Ok(b'b')));
+    assert_matches!(iter.next(), Some(Ok(b'?')));
+    assert_matches!(iter.next(), Some(Ok(b'Z')));
+    assert_matches!(iter.next(), Some(Ok(b'c')));
+    assert_matches!(iter.next(), None);
+    assert_eq!(iter.repaired_count(), 2);
+}
+
+#[test]
+fn test_lenient_errors_still_reported_when_lossy_but_not_lenient() {
+    // `lossy` only recovers from `InvalidByte`/`InvalidHex`/`UnexpectedEnd`;
+    // a lowercase hex escape is still an error unless `lenient` is also set.
+    let options = DecodeOptions::new().lossy(true);
+    let mut decoder = Decoder::with_options(options);
+    assert_eq!(decoder.feed(b'`').unwrap(), None);
+    assert_eq!(decoder.feed(b'f').unwrap(), None);
+    assert_matches!(decoder.feed(b'e'), Err(DecodeError::LowercaseHex(_)));
+}
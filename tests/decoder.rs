@@ -0,0 +1,116 @@
+use tick_encoding::decoder::Decoder;
+use tick_encoding::DecodeError;
+
+#[test]
+fn test_feed() {
+    let mut decoder = Decoder::default();
+    assert_eq!(decoder.feed(b'h').unwrap(), Some(b'h'));
+    assert_eq!(decoder.feed(b'i').unwrap(), Some(b'i'));
+    assert_eq!(decoder.feed(b'`').unwrap(), None);
+    assert_eq!(decoder.feed(b'`').unwrap(), Some(b'`'));
+    assert_eq!(decoder.feed(b'`').unwrap(), None);
+    assert_eq!(decoder.feed(b'0').unwrap(), None);
+    assert_eq!(decoder.feed(b'0').unwrap(), Some(0x00));
+    decoder.finish().unwrap();
+}
+
+#[test]
+fn test_feed_split_escape_across_calls() {
+    let mut decoder = Decoder::default();
+    assert_eq!(decoder.feed(b'`').unwrap(), None);
+    assert_eq!(decoder.feed(b'F').unwrap(), None);
+    assert_eq!(decoder.feed(b'F').unwrap(), Some(0xFF));
+}
+
+#[test]
+fn test_feed_invalid_byte_error() {
+    let mut decoder = Decoder::default();
+    assert!(matches!(
+        decoder.feed(0x00),
+        Err(DecodeError::InvalidByte(0x00))
+    ));
+}
+
+#[test]
+fn test_finish_mid_escape_error() {
+    let mut decoder = Decoder::default();
+    decoder.feed(b'`').unwrap();
+    assert!(matches!(decoder.finish(), Err(DecodeError::UnexpectedEnd)));
+
+    let mut decoder = Decoder::default();
+    decoder.feed(b'`').unwrap();
+    decoder.feed(b'F').unwrap();
+    assert!(matches!(decoder.finish(), Err(DecodeError::UnexpectedEnd)));
+}
+
+#[test]
+fn test_finish_ready_ok() {
+    let mut decoder = Decoder::default();
+    decoder.feed(b'x').unwrap();
+    decoder.finish().unwrap();
+}
+
+#[test]
+fn test_decode_slice() {
+    let mut decoder = Decoder::default();
+    let mut output = [0u8; 16];
+    let (consumed, written) = decoder
+        .decode_slice(b"hello `00`FF", &mut output, true)
+        .unwrap();
+    assert_eq!(consumed, 12);
+    assert_eq!(&output[..written], b"hello \x00\xFF");
+}
+
+#[test]
+fn test_decode_slice_small_output_buffer() {
+    let mut decoder = Decoder::default();
+    let input = b"hello `00`FF";
+    let mut decoded = Vec::new();
+    let mut consumed_total = 0;
+
+    while consumed_total < input.len() {
+        let mut output = [0u8; 3];
+        let (consumed, written) = decoder
+            .decode_slice(&input[consumed_total..], &mut output, false)
+            .unwrap();
+        consumed_total += consumed;
+        decoded.extend_from_slice(&output[..written]);
+    }
+    decoder.finish().unwrap();
+
+    assert_eq!(decoded, b"hello \x00\xFF");
+}
+
+#[test]
+fn test_decode_slice_split_escape_across_calls() {
+    let mut decoder = Decoder::default();
+    let mut output = [0u8; 16];
+
+    let (consumed, written) = decoder.decode_slice(b"hi`", &mut output, false).unwrap();
+    assert_eq!(consumed, 3);
+    assert_eq!(&output[..written], b"hi");
+
+    let (consumed, written) = decoder.decode_slice(b"FF", &mut output, true).unwrap();
+    assert_eq!(consumed, 2);
+    assert_eq!(&output[..written], &[0xFF]);
+}
+
+#[test]
+fn test_decode_slice_invalid_byte_error() {
+    let mut decoder = Decoder::default();
+    let mut output = [0u8; 16];
+    assert!(matches!(
+        decoder.decode_slice(&[0x00], &mut output, true),
+        Err(DecodeError::InvalidByte(0x00))
+    ));
+}
+
+#[test]
+fn test_decode_slice_eof_mid_escape_error() {
+    let mut decoder = Decoder::default();
+    let mut output = [0u8; 16];
+    assert!(matches!(
+        decoder.decode_slice(b"`0", &mut output, true),
+        Err(DecodeError::UnexpectedEnd)
+    ));
+}
@@ -0,0 +1,113 @@
+use tick_encoding::decoder::{DecodeOptions, Decoder, DecoderState};
+
+#[test]
+fn test_into_state_ready() {
+    let decoder = Decoder::default();
+    assert_eq!(decoder.into_state(), DecoderState::Ready);
+}
+
+#[test]
+fn test_into_state_pending_escape() {
+    let mut decoder = Decoder::default();
+    decoder.feed(b'`').unwrap();
+    assert_eq!(decoder.into_state(), DecoderState::PendingEscape);
+}
+
+#[test]
+fn test_into_state_pending_hex_digit() {
+    let mut decoder = Decoder::default();
+    decoder.feed(b'`').unwrap();
+    decoder.feed(b'F').unwrap();
+    assert_eq!(
+        decoder.into_state(),
+        DecoderState::PendingHexDigit { high: b'F' }
+    );
+}
+
+#[test]
+fn test_from_state_resumes_mid_escape() {
+    let state = DecoderState::PendingHexDigit { high: b'F' };
+    let mut decoder = Decoder::from_state(state, DecodeOptions::new());
+    assert_eq!(decoder.feed(b'F').unwrap(), Some(0xFF));
+    decoder.finish().unwrap();
+}
+
+#[test]
+fn test_checkpoint_round_trip_across_split_escape() {
+    let mut decoder = Decoder::default();
+    assert_eq!(decoder.feed(b'h').unwrap(), Some(b'h'));
+    assert_eq!(decoder.feed(b'`').unwrap(), None);
+
+    // Checkpoint mid-escape, as if the process were restarting here.
+    let state = decoder.into_state();
+    let mut decoder = Decoder::from_state(state, DecodeOptions::new());
+
+    assert_eq!(decoder.feed(b'0').unwrap(), None);
+    assert_eq!(decoder.feed(b'0').unwrap(), Some(0x00));
+    decoder.finish().unwrap();
+}
+
+#[test]
+fn test_encode_iter_checkpoint_round_trip() {
+    use tick_encoding::iter::EncodeIter;
+
+    let mut iter = tick_encoding::encode_iter(b"hi\x00".iter().copied());
+    assert_eq!(iter.next(), Some('h'));
+    assert_eq!(iter.next(), Some('i'));
+    assert_eq!(iter.next(), Some('`'));
+
+    // Checkpoint mid-escape, as if the process were restarting here, then
+    // resume over a fresh iterator (here, an already-exhausted one, since
+    // the whole input byte was already consumed to produce the escape).
+    let state = iter.into_state();
+    let mut iter = EncodeIter::from_state(core::iter::empty::<u8>(), state);
+    assert_eq!(iter.next(), Some('0'));
+    assert_eq!(iter.next(), Some('0'));
+    assert_eq!(iter.next(), None);
+}
+
+#[cfg(feature = "serde")]
+mod serde_derive {
+    use super::*;
+
+    #[test]
+    fn test_decoder_serializes_mid_escape() {
+        let mut decoder = Decoder::default();
+        decoder.feed(b'`').unwrap();
+        decoder.feed(b'F').unwrap();
+
+        let json = serde_json::to_string(&decoder).unwrap();
+        let mut restored: Decoder = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.feed(b'F').unwrap(), Some(0xFF));
+    }
+
+    #[test]
+    fn test_encoder_state_serializes_with_pending_output() {
+        use tick_encoding::encoder::{Encoder, EncoderState};
+
+        let mut encoder = Encoder::default();
+        encoder.push(0x00);
+
+        let json = serde_json::to_string(&encoder.into_state()).unwrap();
+        let state: EncoderState = serde_json::from_str(&json).unwrap();
+        let mut restored = Encoder::from_state(state);
+        assert_eq!(restored.next(), Some('0'));
+        assert_eq!(restored.next(), Some('0'));
+        assert_eq!(restored.next(), None);
+    }
+
+    #[test]
+    fn test_encode_iter_state_serializes_mid_escape() {
+        use tick_encoding::iter::EncodeIter;
+
+        let mut iter = tick_encoding::encode_iter(b"\x00".iter().copied());
+        assert_eq!(iter.next(), Some('`'));
+
+        let json = serde_json::to_string(&iter.into_state()).unwrap();
+        let state = serde_json::from_str(&json).unwrap();
+        let mut iter = EncodeIter::from_state(core::iter::empty::<u8>(), state);
+        assert_eq!(iter.next(), Some('0'));
+        assert_eq!(iter.next(), Some('0'));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,58 @@
+use tick_encoding::encoder::Encoder;
+
+#[test]
+fn test_push_and_next() {
+    let mut encoder = Encoder::default();
+    assert_eq!(encoder.push(b'h'), 'h');
+    assert_eq!(encoder.push(b'i'), 'i');
+    assert_eq!(encoder.push(b'`'), '`');
+    assert_eq!(encoder.next(), Some('`'));
+    assert_eq!(encoder.next(), None);
+    assert_eq!(encoder.push(0x00), '`');
+    assert_eq!(encoder.next(), Some('0'));
+    assert_eq!(encoder.next(), Some('0'));
+    assert_eq!(encoder.next(), None);
+}
+
+#[test]
+fn test_encode_slice() {
+    let mut encoder = Encoder::default();
+    let mut output = [0u8; 16];
+    let (consumed, written) = encoder.encode_slice(b"hello `00`FF", &mut output);
+    assert_eq!(consumed, 12);
+    assert_eq!(&output[..written], b"hello ``00``FF");
+}
+
+#[test]
+fn test_encode_slice_small_output_buffer() {
+    let mut encoder = Encoder::default();
+    let input = b"hello \x00\xFF";
+    let mut encoded = Vec::new();
+    let mut consumed_total = 0;
+
+    while consumed_total < input.len() {
+        let mut output = [0u8; 3];
+        let (consumed, written) = encoder.encode_slice(&input[consumed_total..], &mut output);
+        consumed_total += consumed;
+        encoded.extend_from_slice(&output[..written]);
+    }
+    while let Some(c) = encoder.next() {
+        encoded.push(c as u8);
+    }
+
+    assert_eq!(encoded, b"hello `00`FF");
+}
+
+#[test]
+fn test_encode_slice_resumes_pending_output() {
+    let mut encoder = Encoder::default();
+    let mut small_output = [0u8; 1];
+    let (consumed, written) = encoder.encode_slice(&[0x00], &mut small_output);
+    assert_eq!(consumed, 1);
+    assert_eq!(&small_output[..written], b"`");
+
+    let mut output = [0u8; 16];
+    let (consumed, written) = encoder.encode_slice(b"i", &mut output);
+    assert_eq!(consumed, 1);
+    assert_eq!(&output[..written], b"00i");
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "std")]
+
+use std::io::{Read, Write};
+
+use assert_matches::assert_matches;
+use tick_encoding::io::{DecodeReader, DecodeWriter, EncodeReader, EncodeWriter};
+use tick_encoding::DecodeError;
+
+#[test]
+fn test_encode_writer() {
+    let mut output = Vec::new();
+    let mut writer = EncodeWriter::new(&mut output);
+    writer.write_all(b"hello world!").unwrap();
+    writer.write_all(&[0x00, 0xFF]).unwrap();
+    writer.flush().unwrap();
+    assert_eq!(output, b"hello world!`00`FF");
+}
+
+#[test]
+fn test_encode_writer_escapes_backtick() {
+    let mut output = Vec::new();
+    let mut writer = EncodeWriter::new(&mut output);
+    writer.write_all(b"`").unwrap();
+    assert_eq!(output, b"``");
+}
+
+#[test]
+fn test_decode_reader() {
+    let mut reader = DecodeReader::new(&b"hello world!`00`FF"[..]);
+    let mut output = Vec::new();
+    reader.read_to_end(&mut output).unwrap();
+    assert_eq!(output, b"hello world!\x00\xFF");
+}
+
+#[test]
+fn test_decode_reader_one_byte_at_a_time() {
+    let mut reader = DecodeReader::new(&b"x`00`FF"[..]);
+    let mut byte = [0u8; 1];
+
+    assert_eq!(reader.read(&mut byte).unwrap(), 1);
+    assert_eq!(byte, [b'x']);
+    assert_eq!(reader.read(&mut byte).unwrap(), 1);
+    assert_eq!(byte, [0x00]);
+    assert_eq!(reader.read(&mut byte).unwrap(), 1);
+    assert_eq!(byte, [0xFF]);
+    assert_eq!(reader.read(&mut byte).unwrap(), 0);
+}
+
+#[test]
+fn test_decode_reader_invalid_byte_error() {
+    let mut reader = DecodeReader::new(&[0xFF][..]);
+    let mut output = Vec::new();
+    let error = reader.read_to_end(&mut output).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_encode_reader() {
+    let mut reader = EncodeReader::new(&b"hello world!\x00\xFF"[..]);
+    let mut output = Vec::new();
+    reader.read_to_end(&mut output).unwrap();
+    assert_eq!(output, b"hello world!`00`FF");
+}
+
+#[test]
+fn test_encode_reader_escapes_backtick() {
+    let mut reader = EncodeReader::new(&b"`"[..]);
+    let mut output = Vec::new();
+    reader.read_to_end(&mut output).unwrap();
+    assert_eq!(output, b"``");
+}
+
+#[test]
+fn test_encode_reader_one_byte_at_a_time() {
+    let mut reader = EncodeReader::new(&b"x\x00\xFF"[..]);
+    let mut byte = [0u8; 1];
+
+    for expected in b"x`00`FF" {
+        assert_eq!(reader.read(&mut byte).unwrap(), 1);
+        assert_eq!(byte, [*expected]);
+    }
+    assert_eq!(reader.read(&mut byte).unwrap(), 0);
+}
+
+#[test]
+fn test_decode_writer() {
+    let mut output = Vec::new();
+    let mut writer = DecodeWriter::new(&mut output);
+    writer.write_all(b"hello world!`00`FF").unwrap();
+    writer.finish().unwrap();
+    assert_eq!(output, b"hello world!\x00\xFF");
+}
+
+#[test]
+fn test_decode_writer_invalid_byte_error() {
+    let mut output = Vec::new();
+    let mut writer = DecodeWriter::new(&mut output);
+    let error = writer.write_all(&[0xFF]).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_decode_writer_unexpected_end() {
+    let mut output = Vec::new();
+    let mut writer = DecodeWriter::new(&mut output);
+    writer.write_all(b"hi`0").unwrap();
+    assert_matches!(writer.finish(), Err(DecodeError::UnexpectedEnd));
+}
+
+/// A [`Read`] wrapper that only ever returns at most one byte per call, to
+/// adversarially exercise escape sequences split across `read` calls.
+struct OneByteAtATime<R>(R);
+
+impl<R: Read> Read for OneByteAtATime<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = buf.len().min(1);
+        self.0.read(&mut buf[..n])
+    }
+}
+
+#[test]
+fn test_round_trip_one_byte_at_a_time() {
+    let original: Vec<u8> = (0..=255u8).collect();
+
+    let mut encoded = Vec::new();
+    {
+        let mut writer = EncodeWriter::new(&mut encoded);
+        for &byte in &original {
+            writer.write_all(&[byte]).unwrap();
+        }
+    }
+
+    let mut decoded = Vec::new();
+    let mut reader = DecodeReader::new(OneByteAtATime(&encoded[..]));
+    reader.read_to_end(&mut decoded).unwrap();
+
+    assert_eq!(decoded, original);
+}
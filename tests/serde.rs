@@ -0,0 +1,38 @@
+#![cfg(all(feature = "serde", feature = "alloc"))]
+
+use tick_encoding::serde::TickEncoded;
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Message {
+    #[serde(with = "tick_encoding::serde::as_string")]
+    payload: Vec<u8>,
+}
+
+#[test]
+fn test_as_string_round_trip() {
+    let message = Message {
+        payload: vec![0x00, 0xFF, b'h', b'i'],
+    };
+
+    let json = serde_json::to_string(&message).unwrap();
+    assert_eq!(json, r#"{"payload":"`00`FFhi"}"#);
+
+    let decoded: Message = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, message);
+}
+
+#[test]
+fn test_as_string_deserialize_error() {
+    let result: Result<Message, _> = serde_json::from_str(r#"{"payload":"`ZZ"}"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_tick_encoded_round_trip() {
+    let value = TickEncoded(vec![0x00, 0xFF]);
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, "\"`00`FF\"");
+
+    let decoded: TickEncoded = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, value);
+}
@@ -0,0 +1,76 @@
+use tick_encoding::state::{TickDecoderState, TickEncoderState};
+use tick_encoding::DecodeError;
+
+#[test]
+fn test_tick_encoder_state() {
+    let mut state = TickEncoderState::default();
+    let mut output = [0u8; 32];
+
+    let progress = state.step(b"hi `00 there", &mut output).unwrap();
+    assert_eq!(&output[..progress.produced], b"hi ``00 there");
+    assert_eq!(progress.consumed, 12);
+    assert!(progress.done);
+}
+
+#[test]
+fn test_tick_encoder_state_small_output() {
+    let mut state = TickEncoderState::default();
+    let input = [0xFF, 0xFF];
+    let mut output = [0u8; 2];
+
+    let progress = state.step(&input, &mut output).unwrap();
+    assert_eq!(&output[..progress.produced], b"`F");
+    assert_eq!(progress.consumed, 1);
+    assert!(!progress.done);
+
+    // Resume with the unconsumed remainder of the input.
+    let mut output = [0u8; 32];
+    let progress = state.step(&input[progress.consumed..], &mut output).unwrap();
+    assert_eq!(&output[..progress.produced], b"F`FF");
+    assert_eq!(progress.consumed, 1);
+    assert!(progress.done);
+}
+
+#[test]
+fn test_tick_decoder_state() {
+    let mut state = TickDecoderState::default();
+    let mut output = [0u8; 32];
+
+    let progress = state.step(b"hi `00 there", &mut output).unwrap();
+    assert_eq!(&output[..progress.produced], b"hi \x00 there");
+    assert_eq!(progress.consumed, 12);
+    assert!(progress.done);
+}
+
+#[test]
+fn test_tick_decoder_state_split_escape() {
+    let mut state = TickDecoderState::default();
+    let mut output = [0u8; 8];
+
+    let progress = state.step(b"hi`0", &mut output).unwrap();
+    assert_eq!(&output[..progress.produced], b"hi");
+    assert!(!progress.done);
+
+    let progress = state.step(b"0", &mut output).unwrap();
+    assert_eq!(&output[..progress.produced], b"\x00");
+    assert!(progress.done);
+
+    let progress = state.step(b"", &mut output).unwrap();
+    assert_eq!(progress.produced, 0);
+    assert!(progress.done);
+}
+
+#[test]
+fn test_tick_decoder_state_unexpected_end() {
+    let mut state = TickDecoderState::default();
+    let mut output = [0u8; 8];
+
+    let progress = state.step(b"hi`0", &mut output).unwrap();
+    assert_eq!(&output[..progress.produced], b"hi");
+    assert!(!progress.done);
+
+    assert!(matches!(
+        state.step(b"", &mut output),
+        Err(DecodeError::UnexpectedEnd)
+    ));
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "alloc")]
+
+use assert_matches::assert_matches;
+use tick_encoding::{decode_to_vec_utf8, decode_utf8, encode_to_vec_utf8, encode_utf8, DecodeError};
+
+#[test]
+fn test_encode_utf8() {
+    assert_eq!(encode_utf8(b""), "");
+    assert_eq!(encode_utf8(b"hello"), "hello");
+    assert_eq!(encode_utf8(b"`"), "``");
+    assert_eq!(encode_utf8(&[0xFF]), "`FF");
+    assert_eq!(encode_utf8("foo bar 🙂".as_bytes()), "foo bar 🙂");
+    assert_eq!(
+        encode_utf8(b"hello world!\r\n\thi there"),
+        "hello world!\r\n\thi there"
+    );
+}
+
+#[test]
+fn test_encode_utf8_falls_back_for_invalid_sequences() {
+    // Incomplete sequence at the end of the input
+    assert_eq!(encode_utf8(&[0xF0, 0x9F]), "`F0`9F");
+
+    // Overlong encoding of '/' (should be a single byte)
+    assert_eq!(encode_utf8(&[0xC0, 0xAF]), "`C0`AF");
+
+    // Stray continuation byte with no leading byte
+    assert_eq!(encode_utf8(&[0x80]), "`80");
+
+    // Surrogate half encoded as UTF-8 (U+D800)
+    assert_eq!(encode_utf8(&[0xED, 0xA0, 0x80]), "`ED`A0`80");
+}
+
+#[test]
+fn test_decode_utf8() {
+    assert_eq!(decode_utf8(b"").unwrap(), &b""[..]);
+    assert_eq!(decode_utf8(b"hello").unwrap(), &b"hello"[..]);
+    assert_eq!(decode_utf8(b"``").unwrap(), &b"`"[..]);
+    assert_eq!(decode_utf8(b"`FF").unwrap(), &[0xFF][..]);
+    assert_eq!(
+        decode_utf8("foo bar 🙂".as_bytes()).unwrap(),
+        "foo bar 🙂".as_bytes()
+    );
+}
+
+#[test]
+fn test_decode_utf8_invalid_byte_error() {
+    assert_matches!(decode_utf8(&[0x00]), Err(DecodeError::InvalidByte(0x00)));
+    assert_matches!(decode_utf8(&[0x80]), Err(DecodeError::InvalidByte(0x80)));
+    assert_matches!(
+        decode_utf8(&[0xF0, 0x9F]),
+        Err(DecodeError::InvalidByte(0xF0))
+    );
+}
+
+#[test]
+fn test_round_trip_utf8() {
+    let original = "hello `world`! 🙂🙃 \x00\x01".as_bytes();
+    let mut input = original.to_vec();
+    input.extend_from_slice(&[0xFF, 0x80, 0xC0, 0xAF]);
+
+    let encoded = encode_utf8(&input);
+    let decoded = decode_utf8(encoded.as_bytes()).unwrap();
+    assert_eq!(decoded, &input[..]);
+}
+
+#[test]
+fn test_encode_to_vec_utf8() {
+    let mut output = Vec::new();
+    let count = encode_to_vec_utf8("foo bar 🙂".as_bytes(), &mut output);
+    assert_eq!(output, "foo bar 🙂".as_bytes());
+    assert_eq!(count, 12);
+}
+
+#[test]
+fn test_decode_to_vec_utf8() {
+    let mut output = Vec::new();
+    let count = decode_to_vec_utf8("foo bar 🙂".as_bytes(), &mut output).unwrap();
+    assert_eq!(output, "foo bar 🙂".as_bytes());
+    assert_eq!(count, 12);
+}